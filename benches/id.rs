@@ -5,6 +5,8 @@
 //
 // For further detail, please refer to the individual licenses located at the root of this crate.
 
+#![allow(clippy::unusual_byte_groupings)]
+
 use can_types::{conversion::Conversion, protocol::j1939::identifier::IdJ1939};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
@@ -36,13 +38,13 @@ pub fn id_bench(c: &mut Criterion) {
 
     let mut group = c.benchmark_group("id");
     group.throughput(criterion::Throughput::Elements(1));
-    group.bench_function("to_bits", |b| b.iter(|| black_box(id_to_bits(&id))));
+    group.bench_function("to_bits", |b| b.iter(|| id_to_bits(black_box(&id))));
 
     #[cfg(feature = "alloc")]
-    group.bench_function("to_hex", |b| b.iter(|| black_box(id_to_hex(&id))));
+    group.bench_function("to_hex", |b| b.iter(|| id_to_hex(black_box(&id))));
 
-    group.bench_function("from_bits", |b| b.iter(|| black_box(id_from_bits(id_bits))));
-    group.bench_function("from_hex", |b| b.iter(|| black_box(id_from_hex(id_hex))));
+    group.bench_function("from_bits", |b| b.iter(|| id_from_bits(black_box(id_bits))));
+    group.bench_function("from_hex", |b| b.iter(|| id_from_hex(black_box(id_hex))));
     group.finish();
 }
 
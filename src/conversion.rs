@@ -8,8 +8,11 @@
 //! Defines the standard conversion between units of data provided in this crate.
 
 use crate::{
-    payload::{Data, Name, Pdu},
-    protocol::{can2_a::identifier::IdCan2A, can2_b::identifier::IdCan2B},
+    error::Error,
+    payload::{ClassicData, Data, FdData, Name, Pdu},
+    protocol::{
+        can2_a::identifier::IdCan2A, can2_b::identifier::IdCan2B, j1939::identifier::IdJ1939,
+    },
 };
 
 if_alloc! {
@@ -65,12 +68,74 @@ impl From<Pdu<Name>> for Pdu<Data> {
     }
 }
 
+impl From<Pdu<Data>> for Pdu<FdData> {
+    fn from(value: Pdu<Data>) -> Self {
+        // Eight bytes is always a legal CAN FD payload length.
+        Self::try_from_slice(&value.to_be_bytes()).unwrap_or_default()
+    }
+}
+
+impl From<Pdu<Data>> for Pdu<ClassicData> {
+    fn from(value: Pdu<Data>) -> Self {
+        // Eight bytes is always a legal classical payload length.
+        Self::try_from_slice(&value.to_be_bytes()).unwrap_or_default()
+    }
+}
+
+impl From<Pdu<ClassicData>> for Pdu<Data> {
+    /// Widens the classical data field to 8 bytes, filling the missing bytes with zero.
+    fn from(value: Pdu<ClassicData>) -> Self {
+        let mut bytes = [0; 8];
+        bytes[..value.len()].copy_from_slice(value.as_slice());
+
+        Self::from_bits(u64::from_be_bytes(bytes))
+    }
+}
+
+impl From<Pdu<ClassicData>> for Pdu<FdData> {
+    fn from(value: Pdu<ClassicData>) -> Self {
+        // Zero through eight bytes are always legal CAN FD payload lengths.
+        Self::try_from_slice(value.as_slice()).unwrap_or_default()
+    }
+}
+
 impl From<IdCan2A> for IdCan2B {
     fn from(value: IdCan2A) -> Self {
         Self::from_bits(value.into_bits().into())
     }
 }
 
+impl TryFrom<IdCan2B> for IdCan2A {
+    type Error = Error;
+
+    /// Narrows a 29-bit extended identifier into an 11-bit standard identifier.
+    ///
+    /// # Errors
+    /// - [`Error::IdBitsOutOfRange`] if any of the upper 18 bits of the identifier are set.
+    fn try_from(value: IdCan2B) -> Result<Self, Self::Error> {
+        let bits = value.into_bits();
+        let narrowed =
+            u16::try_from(bits).map_err(|_| Error::IdBitsOutOfRange { bits, max: 0x7FF })?;
+
+        Self::try_from_bits(narrowed)
+    }
+}
+
+impl From<IdJ1939> for IdCan2B {
+    fn from(value: IdJ1939) -> Self {
+        Self::from_bits(value.into_bits())
+    }
+}
+
+impl From<IdCan2B> for IdJ1939 {
+    /// Interprets a 29-bit extended identifier as a J1939 identifier.
+    ///
+    /// Every 29-bit value is a structurally valid J1939 identifier, so the conversion is lossless.
+    fn from(value: IdCan2B) -> Self {
+        Self::from_bits(value.into_bits())
+    }
+}
+
 #[cfg(test)]
 mod impl_tests {
     use super::*;
@@ -91,6 +156,26 @@ mod impl_tests {
         assert_eq!(Pdu::<Name>::from_hex("FFFF82DF1AFFFFFF"), name_a);
     }
 
+    #[test]
+    fn test_fd_data_from() {
+        let data_a = Pdu::<Data>::from_hex("FFFF82DF1AFFFFFF");
+        let fd_a = Pdu::<FdData>::from(data_a);
+
+        assert_eq!(8, fd_a.len());
+        assert_eq!(&data_a.to_be_bytes(), fd_a.as_slice());
+    }
+
+    #[test]
+    fn test_classic_data_from() {
+        let data_a = Pdu::<Data>::from_hex("0A0B0C0000000000");
+        let classic_a = Pdu::<ClassicData>::from(data_a);
+        let classic_b = Pdu::<ClassicData>::try_from_hex("0A0B0C").unwrap();
+
+        assert_eq!(8, classic_a.len());
+        assert_eq!(data_a, Pdu::<Data>::from(classic_b));
+        assert_eq!(3, Pdu::<FdData>::from(classic_b).len());
+    }
+
     #[test]
     fn test_extended_from() {
         let id_std_a = IdCan2A::from_hex("00F");
@@ -98,4 +183,37 @@ mod impl_tests {
 
         assert_eq!(IdCan2B::from_hex("0000000F"), id_ext_a);
     }
+
+    #[test]
+    fn test_standard_try_from() {
+        let id_ext_a = IdCan2B::from_hex("000007FF");
+        let id_ext_b = IdCan2B::from_hex("00000800");
+        let id_ext_c = IdCan2B::from_hex("1FFC0000");
+
+        assert_eq!(Ok(IdCan2A::from_hex("7FF")), IdCan2A::try_from(id_ext_a));
+        assert_eq!(
+            Err(Error::IdBitsOutOfRange {
+                bits: 0x800,
+                max: 0x7FF
+            }),
+            IdCan2A::try_from(id_ext_b)
+        );
+        assert_eq!(
+            Err(Error::IdBitsOutOfRange {
+                bits: 0x1FFC_0000,
+                max: 0x7FF
+            }),
+            IdCan2A::try_from(id_ext_c)
+        );
+    }
+
+    #[test]
+    fn test_j1939_from() {
+        let id_j1939_a = IdJ1939::from_hex("0CF00400");
+        let id_ext_a = IdCan2B::from(id_j1939_a);
+
+        assert_eq!(IdCan2B::from_hex("0CF00400"), id_ext_a);
+        assert_eq!(id_j1939_a, IdJ1939::from(id_ext_a));
+        assert_eq!(3, IdJ1939::from(id_ext_a).priority());
+    }
 }
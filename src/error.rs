@@ -0,0 +1,333 @@
+// Copyright (c) 2024 Nathan H. Keough
+//
+// This work is dual-licensed under MIT OR Apache 2.0 (or any later version).
+// You may choose between one of them if you use this work.
+//
+// For further detail, please refer to the individual licenses located at the root of this crate.
+
+//! Defines the [`Error`] type returned by the fallible operations of this crate.
+//!
+//! Every variant carries the offending value so that callers, including `no_std` firmware, can
+//! match on the reason a conversion failed rather than inspecting a message string.
+//!
+//! #### `std` features:
+//! - [`std::error::Error`] implementation
+
+use core::{
+    fmt::{Display, Formatter, Result},
+    num::ParseIntError,
+    time::Duration,
+};
+
+/// Enumerates the reasons an encoding or decoding operation can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The provided string slice is not a valid base-16 (hex) integer of the expected width.
+    /// Contains the underlying parse error.
+    InvalidHex(ParseIntError),
+    /// The provided bits exceed the width of the identifier.
+    /// Contains the offending bits and the largest valid value.
+    IdBitsOutOfRange {
+        /// The offending identifier bits.
+        bits: u32,
+        /// The largest valid identifier value.
+        max: u32,
+    },
+    /// The provided bits exceed the 18-bit width of a Parameter Group Number.
+    /// Contains the offending bits.
+    PgnBitsOutOfRange(u32),
+    /// The provided priority exceeds the 3-bit width of the priority field.
+    /// Contains the offending priority.
+    InvalidPriority(u8),
+    /// The provided string slice contains a character that is not a base-16 (hex) digit.
+    /// Contains the offending character.
+    InvalidHexDigit(char),
+    /// The provided string slice does not contain a whole number of bytes.
+    /// Contains the number of hex digits.
+    OddHexLength(usize),
+    /// The provided number of bytes is not a legal payload length for the data unit.
+    /// Contains the offending length.
+    InvalidDataLength(usize),
+    /// The provided Data Length Code exceeds the 4-bit width of the DLC field.
+    /// Contains the offending DLC.
+    InvalidDlc(u8),
+    /// The identifier uses a different frame format than the one requested.
+    /// Contains the identifier extension (IDE) flag of the offending identifier.
+    UnexpectedIdFormat {
+        /// `true` if the offending identifier uses the extended (29-bit) format.
+        extended: bool,
+    },
+    /// The provided string slice is not a valid textual representation of a frame.
+    MalformedFrame,
+    /// A J1939 identifier was built without a source address.
+    MissingSourceAddr,
+    /// A destination address was provided for a PDU2 (broadcast) Parameter Group Number.
+    /// Contains the offending PGN bits.
+    UnexpectedDestinationAddr(u32),
+    /// The provided value exceeds the bit width of a field.
+    /// Contains the name of the field, the offending value and the largest valid value.
+    FieldOutOfRange {
+        /// The name of the field.
+        field: &'static str,
+        /// The offending value.
+        value: u32,
+        /// The largest valid value of the field.
+        max: u32,
+    },
+    /// The provided signal layout does not fit in the data field.
+    /// Contains the offending start bit and length.
+    InvalidSignal {
+        /// The offending start bit.
+        start_bit: u8,
+        /// The offending length in bits.
+        length: u8,
+    },
+    /// The provided raw signal value does not fit in the signal.
+    /// Contains the length of the signal in bits.
+    SignalOutOfRange {
+        /// The length of the signal in bits.
+        length: u8,
+    },
+    /// The physical value of a signal lies outside its valid range.
+    PhysicalOutOfRange,
+    /// The message carries a different Parameter Group Number than the one expected.
+    /// Contains the offending PGN bits.
+    UnexpectedPgn(u32),
+    /// The control byte of a transport protocol connection management message is unknown.
+    /// Contains the offending control byte.
+    InvalidControlByte(u8),
+    /// A transport protocol session was aborted.
+    /// Contains the reason for the abort.
+    TransportAborted(AbortReason),
+    /// A transport protocol packet arrived outside the allowed time window.
+    /// Contains the time elapsed since the previous packet.
+    TransportTiming(Duration),
+    /// The string does not name a known J1939 address.
+    UnknownAddrName,
+}
+
+impl Error {
+    /// Checks that `value` fits in a field whose largest valid value is `max`.
+    ///
+    /// # Errors
+    /// - [`Error::FieldOutOfRange`] if `value` exceeds `max`.
+    pub(crate) fn check_field(
+        field: &'static str,
+        value: u32,
+        max: u32,
+    ) -> core::result::Result<(), Self> {
+        if value > max {
+            return Err(Error::FieldOutOfRange { field, value, max });
+        }
+
+        Ok(())
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(value: ParseIntError) -> Self {
+        Self::InvalidHex(value)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Error::InvalidHex(e) => write!(f, "Invalid hexadecimal string slice - {e}"),
+            Error::IdBitsOutOfRange { bits, max } => write!(
+                f,
+                "Identifier bits out of range! Valid range is 0x0..={max:#X} - got {bits:#X}"
+            ),
+            Error::PgnBitsOutOfRange(bits) => write!(
+                f,
+                "PGN bits out of range! Valid range is 0x0..=0x3FFFF - got {bits:#X}"
+            ),
+            Error::InvalidPriority(priority) => write!(
+                f,
+                "Invalid priority! The priority value must be between 0 and 7 inclusive - got {priority}"
+            ),
+            Error::InvalidHexDigit(c) => write!(f, "Invalid hexadecimal digit - got {c:?}"),
+            Error::OddHexLength(len) => write!(
+                f,
+                "Hexadecimal string slice must contain an even number of digits - got {len}"
+            ),
+            Error::InvalidDataLength(len) => {
+                write!(f, "Invalid payload length for the data unit - got {len} bytes")
+            }
+            Error::InvalidDlc(dlc) => write!(
+                f,
+                "Invalid DLC! The DLC value must be between 0 and 15 inclusive - got {dlc}"
+            ),
+            Error::UnexpectedIdFormat { extended: true } => {
+                write!(f, "Unexpected identifier format - got an extended (29-bit) identifier")
+            }
+            Error::UnexpectedIdFormat { extended: false } => {
+                write!(f, "Unexpected identifier format - got a standard (11-bit) identifier")
+            }
+            Error::MalformedFrame => write!(f, "Malformed frame string slice"),
+            Error::MissingSourceAddr => write!(f, "Missing source address"),
+            Error::UnexpectedDestinationAddr(pgn) => write!(
+                f,
+                "Unexpected destination address! PDU2 PGNs are broadcast only - got PGN {pgn}"
+            ),
+            Error::FieldOutOfRange { field, value, max } => write!(
+                f,
+                "Field {field} out of range! Valid range is 0..={max} - got {value}"
+            ),
+            Error::InvalidSignal { start_bit, length } => write!(
+                f,
+                "Invalid signal! A {length}-bit signal at start bit {start_bit} does not fit in the data field"
+            ),
+            Error::SignalOutOfRange { length } => {
+                write!(f, "Signal value out of range for a {length}-bit signal")
+            }
+            Error::PhysicalOutOfRange => write!(f, "Physical value out of range for the signal"),
+            Error::UnexpectedPgn(pgn) => write!(f, "Unexpected PGN - got PGN {pgn}"),
+            Error::InvalidControlByte(control) => {
+                write!(f, "Invalid transport protocol control byte - got {control}")
+            }
+            Error::TransportAborted(reason) => {
+                write!(f, "Transport protocol session aborted - {reason}")
+            }
+            Error::TransportTiming(elapsed) => write!(
+                f,
+                "Transport protocol timing violation - got {} ms between packets",
+                elapsed.as_millis()
+            ),
+            Error::UnknownAddrName => write!(f, "Unknown J1939 address name"),
+        }
+    }
+}
+
+/// Represents the reason a transport session was aborted.
+///
+/// Sent in TP.Conn_Abort messages and carried by [`Error::TransportAborted`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbortReason {
+    /// Already in one or more connection-managed sessions and cannot support another.
+    AlreadyInSession,
+    /// System resources were needed for another task so this session was terminated.
+    ResourcesNeeded,
+    /// A timeout occurred and this is the connection abort to close the session.
+    Timeout,
+    /// A CTS was received while a data transfer was in progress.
+    CtsWhileTransferring,
+    /// The maximum retransmit request limit was reached.
+    MaxRetransmit,
+    /// An unexpected data transfer packet was received.
+    UnexpectedDataTransfer,
+    /// A data transfer packet had a bad sequence number.
+    BadSequenceNumber,
+    /// A data transfer packet had a duplicate sequence number.
+    DuplicateSequenceNumber,
+    /// The total message size is greater than 1785 bytes.
+    MessageTooLarge,
+    /// Any other or reserved reason.
+    /// Contains the reason value.
+    Other(u8),
+}
+
+impl From<u8> for AbortReason {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => AbortReason::AlreadyInSession,
+            2 => AbortReason::ResourcesNeeded,
+            3 => AbortReason::Timeout,
+            4 => AbortReason::CtsWhileTransferring,
+            5 => AbortReason::MaxRetransmit,
+            6 => AbortReason::UnexpectedDataTransfer,
+            7 => AbortReason::BadSequenceNumber,
+            8 => AbortReason::DuplicateSequenceNumber,
+            9 => AbortReason::MessageTooLarge,
+            r => AbortReason::Other(r),
+        }
+    }
+}
+
+impl From<AbortReason> for u8 {
+    fn from(value: AbortReason) -> Self {
+        match value {
+            AbortReason::AlreadyInSession => 1,
+            AbortReason::ResourcesNeeded => 2,
+            AbortReason::Timeout => 3,
+            AbortReason::CtsWhileTransferring => 4,
+            AbortReason::MaxRetransmit => 5,
+            AbortReason::UnexpectedDataTransfer => 6,
+            AbortReason::BadSequenceNumber => 7,
+            AbortReason::DuplicateSequenceNumber => 8,
+            AbortReason::MessageTooLarge => 9,
+            AbortReason::Other(r) => r,
+        }
+    }
+}
+
+impl Display for AbortReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match *self {
+            AbortReason::AlreadyInSession => {
+                write!(f, "Already in one or more connection-managed sessions")
+            }
+            AbortReason::ResourcesNeeded => {
+                write!(f, "System resources were needed for another task")
+            }
+            AbortReason::Timeout => write!(f, "Timeout"),
+            AbortReason::CtsWhileTransferring => {
+                write!(f, "CTS received while a data transfer was in progress")
+            }
+            AbortReason::MaxRetransmit => write!(f, "Maximum retransmit request limit reached"),
+            AbortReason::UnexpectedDataTransfer => write!(f, "Unexpected data transfer packet"),
+            AbortReason::BadSequenceNumber => write!(f, "Bad sequence number"),
+            AbortReason::DuplicateSequenceNumber => write!(f, "Duplicate sequence number"),
+            AbortReason::MessageTooLarge => {
+                write!(f, "Total message size is greater than 1785 bytes")
+            }
+            AbortReason::Other(r) => write!(f, "Other({r})"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidHex(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod error_tests {
+    if_alloc! {
+        use alloc::format;
+    }
+
+    use super::*;
+
+    #[test]
+    fn test_from_parse_int_error() {
+        let parse_err = u32::from_str_radix("XYZ", 16).unwrap_err();
+
+        assert_eq!(Error::InvalidHex(parse_err.clone()), Error::from(parse_err));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_display() {
+        let err_a = Error::IdBitsOutOfRange {
+            bits: 0x2000_0000,
+            max: 0x1FFF_FFFF,
+        };
+        let err_b = Error::InvalidPriority(8);
+
+        assert_eq!(
+            "Identifier bits out of range! Valid range is 0x0..=0x1FFFFFFF - got 0x20000000",
+            format!("{err_a}")
+        );
+        assert_eq!(
+            "Invalid priority! The priority value must be between 0 and 7 inclusive - got 8",
+            format!("{err_b}")
+        );
+    }
+}
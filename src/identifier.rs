@@ -12,10 +12,247 @@
 //! over different protocol types (P) that conform to the [`IsProtocol`] trait, Rust's
 //! monomorphization ensures efficient code generation at compile-time while avoiding namespace
 //! pollution from dozens of individual types.
+//!
+//! [`AnyId`] complements [`Id`] for buses that mix standard and extended frames.
+
+use core::cmp::Ordering;
+
+use crate::{
+    error::Error,
+    protocol::{
+        can2_a::identifier::IdCan2A, can2_b::identifier::IdCan2B, j1939::identifier::IdJ1939,
+    },
+};
 
 /// Marks a type, relating it to a specific protocol.
-pub trait IsProtocol {}
+pub trait IsProtocol {
+    /// The integer type holding the raw bits of an identifier of this protocol.
+    type Bits;
+}
 
 /// Represents a Controller Area Network (CAN) identifier of a specific protocol.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Id<P: IsProtocol>(pub(crate) P);
+
+/// Represents a Controller Area Network (CAN) identifier of either frame format.
+///
+/// A single bus may carry both standard (11-bit) and extended (29-bit) frames. [`AnyId`] holds an
+/// identifier of either format together with its identifier extension (IDE) flag.
+///
+/// ### Ordering
+/// [`AnyId`] is ordered by bus arbitration priority, lowest value winning:
+/// 1. The 11-bit base identifier is compared first. An extended identifier contributes its 11 most
+///    significant bits.
+/// 2. On a tie, a standard identifier wins over an extended one, since its dominant IDE bit is
+///    transmitted where the extended frame sends a recessive one.
+/// 3. Extended identifiers with equal base bits are then ordered by their 18-bit extension.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// let std_a = AnyId::from(IdCan2A::from_bits(0x100));
+/// let ext_a = AnyId::from(IdCan2B::from_bits(0x100 << 18));
+/// let ext_b = AnyId::from(IdCan2B::from_bits(0x0FF << 18 | 0x3FFFF));
+///
+/// assert!(std_a < ext_a);
+/// assert!(ext_b < std_a);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnyId {
+    /// An 11-bit identifier sent in the base frame format (IDE = 0).
+    Standard(IdCan2A),
+    /// A 29-bit identifier sent in the extended frame format (IDE = 1).
+    Extended(IdCan2B),
+}
+
+impl AnyId {
+    /// Returns the identifier extension (IDE) flag.
+    ///
+    /// # Returns
+    /// - `false` for a standard (11-bit) identifier.
+    /// - `true` for an extended (29-bit) identifier.
+    #[inline]
+    #[must_use]
+    pub const fn ide(&self) -> bool {
+        match self {
+            AnyId::Standard(_) => false,
+            AnyId::Extended(_) => true,
+        }
+    }
+
+    /// Returns `true` if the identifier uses the standard (11-bit) format.
+    #[inline]
+    #[must_use]
+    pub const fn is_standard(&self) -> bool {
+        !self.ide()
+    }
+
+    /// Returns `true` if the identifier uses the extended (29-bit) format.
+    #[inline]
+    #[must_use]
+    pub const fn is_extended(&self) -> bool {
+        self.ide()
+    }
+
+    /// Returns the value of the identifier, widened to 32 bits.
+    #[inline]
+    #[must_use]
+    pub const fn id(&self) -> u32 {
+        match self {
+            AnyId::Standard(id) => id.id() as u32,
+            AnyId::Extended(id) => id.id(),
+        }
+    }
+
+    /// Returns the 11-bit base identifier, the part sent before the IDE bit.
+    ///
+    /// For an extended identifier, these are its 11 most significant bits.
+    #[inline]
+    #[must_use]
+    pub const fn base_id(&self) -> u16 {
+        match self {
+            AnyId::Standard(id) => id.id(),
+            #[allow(clippy::cast_possible_truncation)]
+            AnyId::Extended(id) => (id.id() >> 18) as u16,
+        }
+    }
+
+    /// Returns the 18-bit identifier extension, or zero for a standard identifier.
+    #[inline]
+    #[must_use]
+    pub const fn extension(&self) -> u32 {
+        match self {
+            AnyId::Standard(_) => 0,
+            AnyId::Extended(id) => id.id() & 0x3_FFFF,
+        }
+    }
+}
+
+impl PartialOrd for AnyId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AnyId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.base_id(), self.ide(), self.extension()).cmp(&(
+            other.base_id(),
+            other.ide(),
+            other.extension(),
+        ))
+    }
+}
+
+impl From<IdCan2A> for AnyId {
+    fn from(value: IdCan2A) -> Self {
+        AnyId::Standard(value)
+    }
+}
+
+impl From<IdCan2B> for AnyId {
+    fn from(value: IdCan2B) -> Self {
+        AnyId::Extended(value)
+    }
+}
+
+impl From<IdJ1939> for AnyId {
+    fn from(value: IdJ1939) -> Self {
+        AnyId::Extended(value.into())
+    }
+}
+
+impl TryFrom<AnyId> for IdCan2A {
+    type Error = Error;
+
+    fn try_from(value: AnyId) -> Result<Self, Self::Error> {
+        match value {
+            AnyId::Standard(id) => Ok(id),
+            AnyId::Extended(_) => Err(Error::UnexpectedIdFormat { extended: true }),
+        }
+    }
+}
+
+impl TryFrom<AnyId> for IdCan2B {
+    type Error = Error;
+
+    fn try_from(value: AnyId) -> Result<Self, Self::Error> {
+        match value {
+            AnyId::Standard(_) => Err(Error::UnexpectedIdFormat { extended: false }),
+            AnyId::Extended(id) => Ok(id),
+        }
+    }
+}
+
+impl TryFrom<AnyId> for IdJ1939 {
+    type Error = Error;
+
+    fn try_from(value: AnyId) -> Result<Self, Self::Error> {
+        match value {
+            AnyId::Standard(_) => Err(Error::UnexpectedIdFormat { extended: false }),
+            AnyId::Extended(id) => Ok(id.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod any_id_tests {
+    use super::*;
+    use crate::conversion::Conversion;
+
+    #[test]
+    fn test_arbitration_order() {
+        let std_a = AnyId::from(IdCan2A::from_bits(0x100));
+        let std_b = AnyId::from(IdCan2A::from_bits(0x0FF));
+        let ext_a = AnyId::from(IdCan2B::from_bits(0x100 << 18));
+        let ext_b = AnyId::from(IdCan2B::from_bits(0x100 << 18 | 0x1));
+        let ext_c = AnyId::from(IdCan2B::from_bits(0x0FF << 18 | 0x3_FFFF));
+
+        let mut ids = [ext_b, std_a, ext_a, ext_c, std_b];
+        ids.sort();
+
+        assert_eq!([std_b, ext_c, std_a, ext_a, ext_b], ids);
+    }
+
+    #[test]
+    fn test_accessors() {
+        let std_a = AnyId::from(IdCan2A::from_bits(0x7DF));
+        let ext_a = AnyId::from(IdCan2B::from_bits(0x18DA_F110));
+
+        assert!(std_a.is_standard());
+        assert!(!std_a.ide());
+        assert_eq!(0x7DF, std_a.id());
+        assert_eq!(0x7DF, std_a.base_id());
+        assert_eq!(0, std_a.extension());
+
+        assert!(ext_a.is_extended());
+        assert!(ext_a.ide());
+        assert_eq!(0x18DA_F110, ext_a.id());
+        assert_eq!(0x636, ext_a.base_id());
+        assert_eq!(0x2_F110, ext_a.extension());
+    }
+
+    #[test]
+    fn test_conversions() {
+        let id_2a = IdCan2A::from_bits(0x7DF);
+        let id_2b = IdCan2B::from_bits(0x18DA_F110);
+        let id_j1939 = IdJ1939::from_bits(0x0CF0_0400);
+
+        assert_eq!(Ok(id_2a), IdCan2A::try_from(AnyId::from(id_2a)));
+        assert_eq!(Ok(id_2b), IdCan2B::try_from(AnyId::from(id_2b)));
+        assert_eq!(Ok(id_j1939), IdJ1939::try_from(AnyId::from(id_j1939)));
+
+        assert_eq!(
+            Err(Error::UnexpectedIdFormat { extended: true }),
+            IdCan2A::try_from(AnyId::from(id_2b))
+        );
+        assert_eq!(
+            Err(Error::UnexpectedIdFormat { extended: false }),
+            IdCan2B::try_from(AnyId::from(id_2a))
+        );
+        assert_eq!(
+            Err(Error::UnexpectedIdFormat { extended: false }),
+            IdJ1939::try_from(AnyId::from(id_2a))
+        );
+    }
+}
@@ -22,12 +22,18 @@
 //!
 //! ### `no_std` limitations:
 //! - This mode does not support generating [`String`](alloc::string::String) or string slice hexadecimal representations of the provided data structures.
+//! - This mode does not implement [`std::error::Error`] for [`Error`](crate::error::Error). The `std` feature
+//!   (enabled by default) provides it, while the `alloc` feature can be enabled on its own for allocator-only targets.
+//!
+//! ## Optional Features
+//! - `manufacturer-db`: Adds a table of SAE manufacturer codes, used to translate the manufacturer
+//!   code of a J1939 NAME into a company name.
 //!
 //! # Examples
 //! ## Hex to J1939 Identifier
 //! ```rust
 //! # use can_types::prelude::*;
-//! # fn main() -> Result<(), anyhow::Error> {
+//! # fn main() -> Result<(), can_types::error::Error> {
 //! let id_a = IdJ1939::try_from_hex("0CF00400")?;
 //!
 //! assert_eq!(3, id_a.priority());
@@ -40,7 +46,7 @@
 //! ## Decode J1939 PGN
 //! ```rust
 //! # use can_types::prelude::*;
-//! # fn main() -> Result<(), anyhow::Error> {
+//! # fn main() -> Result<(), can_types::error::Error> {
 //! let id_a = IdJ1939::try_from_hex("18FEF200")?;
 //!
 //! assert_eq!(CommunicationMode::Broadcast, id_a.pgn().communication_mode());
@@ -75,27 +81,51 @@ if_alloc! {
     extern crate alloc;
 }
 
+#[cfg(feature = "std")]
+extern crate std;
+
 pub mod conversion;
+pub mod error;
 pub mod identifier;
 pub mod message;
 pub mod payload;
 pub mod protocol;
+pub mod signal;
 
 #[doc(hidden)]
 pub mod prelude {
-    use super::{conversion, identifier, message, payload, protocol};
+    use super::{conversion, error, identifier, message, payload, protocol, signal};
 
     pub use conversion::Conversion;
-    pub use identifier::{Id, IsProtocol};
-    pub use message::Message;
-    pub use payload::{Data, IsDataUnit, Name, Pdu};
+    pub use error::Error;
+    pub use identifier::{AnyId, Id, IsProtocol};
+    pub use message::{FrameKind, Message};
+    pub use payload::{ClassicData, Data, FdData, IsDataUnit, Name, NameBuilder, Pdu};
     pub use protocol::{
         can2_a::identifier::{Can2A, IdCan2A},
         can2_b::identifier::{Can2B, IdCan2B},
         j1939::{
             address::{Addr, DestinationAddr, SourceAddr},
+            diagnostic::{
+                spn_label, BroadcastCommand, DiagnosticRequest, Dm1, Dm12, Dm13, Dm13Network, Dm2,
+                Dtc, Dtcs, FlashStatus, Fmi, Lamp, LampStatus, Lamps, SuspendSignal,
+            },
             identifier::{IdJ1939, J1939},
-            pgn::{CommunicationMode, GroupExtension, PduAssignment, PduFormat, Pgn},
+            name::{IndustryGroup, VehicleSystem},
+            network::{AddressClaimer, ClaimState},
+            pgn::{
+                CommunicationMode, GroupExtension, IdJ1939Builder, KnownPgn, PduAssignment,
+                PduFormat, Pgn, TransmissionRate,
+            },
+            spn::{decode_spns, Spn, SPNS},
+            transport::{
+                AbortReason, BamReceiver, Reassembled, RtsCtsOriginator, RtsCtsResponder, Segments,
+                TpCm, TpDt, MAX_TP_SIZE, TP_DT_LEN,
+            },
         },
     };
+    pub use signal::{ByteOrder, PhysicalValue, SignalSpec, SignalValue};
+
+    #[cfg(feature = "manufacturer-db")]
+    pub use protocol::j1939::manufacturer::{Manufacturer, MANUFACTURERS};
 }
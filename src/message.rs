@@ -6,199 +6,303 @@
 // For further detail, please refer to the individual licenses located at the root of this crate.
 
 //! Defines a CAN message (Identifier with associated PDU).
+//!
+//! [`Message`] is generic over the protocol of its identifier and the kind of its data unit, so
+//! the same constructors and accessors serve 11-bit [`Can2A`](crate::protocol::can2_a::identifier::Can2A),
+//! 29-bit [`Can2B`](crate::protocol::can2_b::identifier::Can2B) and
+//! [`J1939`](crate::protocol::j1939::identifier::J1939) traffic alike.
+//!
+//! Each message also records its [`FrameKind`], so remote, error and overload frames can be
+//! round-tripped next to ordinary data frames.
+//!
+//! ### Text format
+//! `Message<P, ClassicData>` implements [`FromStr`] and, with `alloc`, [`Display`] using the
+//! `candump` log notation, extended with tokens for error and overload frames:
+//!
+//! | Frame kind | Format         | Example      |
+//! |------------|----------------|--------------|
+//! | Data       | `<id>#<data>`  | `7DF#020100` |
+//! | Remote     | `<id>#R[<dlc>]`| `7DF#R3`     |
+//! | Error      | `<id>#E`       | `000#E`      |
+//! | Overload   | `<id>#O`       | `000#O`      |
+
+use core::str::FromStr;
+
+if_alloc! {
+    use crate::alloc::fmt::{Display, Formatter, Result as FmtResult};
+}
 
 use crate::{
     conversion::Conversion,
+    error::Error,
     identifier::{Id, IsProtocol},
-    payload::{Data, IsDataUnit, Name, Pdu},
-    protocol::j1939::identifier::J1939,
+    payload::{ClassicData, Data, FdData, IsDataUnit, Name, Pdu},
 };
 
+/// Represents the kind of frame a [`Message`] was sent as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum FrameKind {
+    /// A data frame carrying the payload of the message.
+    #[default]
+    Data,
+    /// A remote transmission request (RTR) frame, which carries no data.
+    /// Contains the requested Data Length Code (DLC).
+    Remote(u8),
+    /// An error frame, signalling a bus error detected by a node.
+    Error,
+    /// An overload frame, requesting a delay before the next frame.
+    Overload,
+}
+
 /// Represents a CAN message with its associated identifier ([`Id`]) and protocol data unit ([`Pdu`]).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Message<P: IsProtocol, U: IsDataUnit> {
     id: Id<P>,
     pdu: Pdu<U>,
+    kind: FrameKind,
 }
 
-impl Message<J1939, Data> {
-    /// Constructs a new Message from its parts: a 29-bit J1939 identifier and pdu containing 64 bits of generic data.
+impl<P, U> Message<P, U>
+where
+    P: IsProtocol + Copy,
+    U: IsDataUnit + Copy,
+{
+    /// Constructs a new [`Message`] from its parts: an identifier and pdu.
     ///
     /// # Arguments
-    /// - `id`: An [`Id<J1939>`] representing the 29-bit identifier of the message.
-    /// - `pdu`: A [`Pdu<Data>`] containing the payload or content of the message.
+    /// - `id`: An [`Id<P>`] representing the identifier of the message.
+    /// - `pdu`: A [`Pdu<U>`] containing the payload or content of the message.
     ///
     /// # Returns
     /// A new [`Message`] instance initialized with the provided parts.
     #[inline]
     #[must_use]
-    pub fn from_parts(id: Id<J1939>, pdu: Pdu<Data>) -> Self {
-        Self { id, pdu }
+    pub fn from_parts(id: Id<P>, pdu: Pdu<U>) -> Self {
+        Self {
+            id,
+            pdu,
+            kind: FrameKind::Data,
+        }
     }
 
-    /// Destructures the [`Message`] into its parts: a 29-bit J1939 identifier and pdu containing 64 bits of generic data.
+    /// Destructures the [`Message`] into its parts: an identifier and pdu.
     ///
     /// # Returns
     /// A tuple containing:
-    /// - An [`Id<J1939>`] representing the 29-bit identifier.
-    /// - A [`Pdu<Data>`] containing the payload or content of the message.
+    /// - An [`Id<P>`] representing the identifier.
+    /// - A [`Pdu<U>`] containing the payload or content of the message.
     #[inline]
     #[must_use]
-    pub fn into_parts(self) -> (Id<J1939>, Pdu<Data>) {
+    pub fn into_parts(self) -> (Id<P>, Pdu<U>) {
         (self.id, self.pdu)
     }
 
-    /// Constructs a new [`Message`] from raw bit representations of its components.
-    /// # Errors
-    /// - If failed to construct the identifier field from bits
-    /// - If failed to construct the pdu field from bits
+    /// Retrieves the identifier from the message.
+    ///
+    /// # Returns
+    /// The [`Id<P>`] bitfield associated with the message.
     #[inline]
-    pub fn try_from_bits(hex_id: u32, hex_pdu: u64) -> Result<Self, anyhow::Error> {
-        let id = Id::<J1939>::from_bits(hex_id);
-        let pdu = Pdu::<Data>::try_from_bits(hex_pdu)?;
-
-        Ok(Self { id, pdu })
+    #[must_use]
+    pub fn id(&self) -> Id<P> {
+        self.id
     }
 
-    /// Constructs a new [`Message`] from hexadecimal string representations of its components.
-    /// # Errors
-    /// - If failed to construct the identifier field from hex
-    /// - If failed to construct the pdu field from hex
+    /// Retrieves the pdu from the message.
+    ///
+    /// # Returns
+    /// The [`Pdu<U>`] associated with the message.
     #[inline]
-    pub fn try_from_hex(hex_id: &str, hex_pdu: &str) -> Result<Self, anyhow::Error> {
-        let id = Id::<J1939>::try_from_hex(hex_id)?;
-        let pdu = Pdu::<Data>::try_from_hex(hex_pdu)?;
-
-        Ok(Self { id, pdu })
+    #[must_use]
+    pub fn pdu(&self) -> Pdu<U> {
+        self.pdu
     }
 
-    /// Constructs a new [`Message`] from raw bit representations of its components.
-    ///
-    /// # Arguments
-    /// - `hex_id`: A `u32` representing the hexadecimal encoded 29-bit J1939 identifier.
-    /// - `hex_pdu`: A `u64` representing the hexadecimal encoded pdu.
+    /// Retrieves the kind of frame the message was sent as.
     ///
     /// # Returns
-    /// A new [`Message`] instance initialized with the decoded components.
+    /// The [`FrameKind`] associated with the message.
     #[inline]
     #[must_use]
-    pub fn from_bits(hex_id: u32, hex_pdu: u64) -> Self {
-        let id = Id::<J1939>::from_bits(hex_id);
-        let pdu = Pdu::<Data>::from_bits(hex_pdu);
-
-        Self { id, pdu }
+    pub fn kind(&self) -> FrameKind {
+        self.kind
     }
 
-    /// Constructs a new [`Message`] from hexadecimal string representations of its components.
-    ///
-    /// # Arguments
-    /// - `hex_id`: A `&str` representing the hexadecimal encoded 29-bit J1939 identifier.
-    /// - `hex_pdu`: A `&str` representing the hexadecimal encoded pdu.
-    ///
-    /// # Returns
-    /// A new [`Message`] instance initialized with the decoded components.
+    /// Returns `true` if the message is a data frame.
     #[inline]
     #[must_use]
-    pub fn from_hex(hex_id: &str, hex_pdu: &str) -> Self {
-        let id = Id::<J1939>::from_hex(hex_id);
-        let pdu = Pdu::<Data>::from_hex(hex_pdu);
+    pub fn is_data(&self) -> bool {
+        matches!(self.kind, FrameKind::Data)
+    }
 
-        Self { id, pdu }
+    /// Returns `true` if the message is a remote transmission request (RTR) frame.
+    #[inline]
+    #[must_use]
+    pub fn is_remote(&self) -> bool {
+        matches!(self.kind, FrameKind::Remote(_))
     }
 
-    /// Retrieves the 29-bit J1939 identifier from the message.
-    ///
-    /// # Returns
-    /// The [`Id<J1939>`] bitfield associated with the message.
+    /// Returns `true` if the message is an error frame.
     #[inline]
     #[must_use]
-    pub fn id(&self) -> Id<J1939> {
-        self.id
+    pub fn is_error(&self) -> bool {
+        matches!(self.kind, FrameKind::Error)
     }
 
-    /// Retrieves the pdu from the message.
-    ///
-    /// # Returns
-    /// The [`Pdu<Data>`] bitfield associated with the message.
+    /// Returns `true` if the message is an overload frame.
     #[inline]
     #[must_use]
-    pub fn pdu(&self) -> Pdu<Data> {
-        self.pdu
+    pub fn is_overload(&self) -> bool {
+        matches!(self.kind, FrameKind::Overload)
     }
 }
 
-impl Message<J1939, Name> {
-    /// Constructs a new [`Message`] from its parts: an identifier and pdu.
+impl<P, U> Message<P, U>
+where
+    P: IsProtocol + Copy,
+    U: IsDataUnit + Copy,
+    Pdu<U>: Default,
+{
+    /// Constructs a new remote transmission request (RTR) [`Message`] with an empty pdu.
     ///
     /// # Arguments
-    /// - `id`: An [`Id<J1939>`] representing the 29-bit identifier of the message.
-    /// - `pdu`: A [`Pdu<Data>`] containing the payload or content of the message.
+    /// - `id`: An [`Id<P>`] representing the identifier of the requested message.
+    /// - `dlc`: The Data Length Code (DLC) of the requested message.
     ///
-    /// # Returns
-    /// A new [`Message`] instance initialized with the provided parts.
-    #[inline]
+    /// # Errors
+    /// - [`Error::InvalidDlc`] if `dlc` exceeds 15.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let msg_a = Message::<Can2A, ClassicData>::remote(IdCan2A::from_hex("7DF"), 8).unwrap();
+    ///
+    /// assert_eq!(FrameKind::Remote(8), msg_a.kind());
+    /// assert!(msg_a.pdu().is_empty());
+    /// ```
+    pub fn remote(id: Id<P>, dlc: u8) -> Result<Self, Error> {
+        if dlc > 0xF {
+            return Err(Error::InvalidDlc(dlc));
+        }
+
+        Ok(Self {
+            id,
+            pdu: Pdu::default(),
+            kind: FrameKind::Remote(dlc),
+        })
+    }
+
+    /// Constructs a new error frame [`Message`] with an empty pdu.
+    ///
+    /// # Arguments
+    /// - `id`: An [`Id<P>`] recorded alongside the error frame, typically zero.
     #[must_use]
-    pub fn from_parts(id: Id<J1939>, pdu: Pdu<Name>) -> Self {
-        Self { id, pdu }
+    pub fn error(id: Id<P>) -> Self {
+        Self {
+            id,
+            pdu: Pdu::default(),
+            kind: FrameKind::Error,
+        }
     }
 
-    /// Destructures the [`Message`] into its parts: an identifier and pdu.
+    /// Constructs a new overload frame [`Message`] with an empty pdu.
     ///
-    /// # Returns
-    /// A tuple containing:
-    /// - An [`Id<J1939>`] representing the 29-bit identifier.
-    /// - A [`Pdu<Data>`] containing the payload or content of the message.
-    #[inline]
+    /// # Arguments
+    /// - `id`: An [`Id<P>`] recorded alongside the overload frame, typically zero.
     #[must_use]
-    pub fn into_parts(self) -> (Id<J1939>, Pdu<Name>) {
-        (self.id, self.pdu)
+    pub fn overload(id: Id<P>) -> Self {
+        Self {
+            id,
+            pdu: Pdu::default(),
+            kind: FrameKind::Overload,
+        }
     }
+}
 
+impl<P, U> Message<P, U>
+where
+    P: IsProtocol,
+    U: IsDataUnit,
+    Id<P>: Conversion<P::Bits, Error = Error>,
+    Pdu<U>: Conversion<u64, Error = Error>,
+{
     /// Constructs a new [`Message`] from raw bit representations of its components.
     /// # Errors
     /// - If failed to construct the identifier field from bits
     /// - If failed to construct the pdu field from bits
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let msg_a = Message::<Can2A, Data>::try_from_bits(0x0F, 0xFF).unwrap();
+    /// let msg_b = Message::<Can2A, Data>::try_from_bits(0x800, 0xFF);
+    ///
+    /// assert_eq!(IdCan2A::from_bits(0x0F), msg_a.id());
+    /// assert!(msg_b.is_err());
+    /// ```
     #[inline]
-    pub fn try_from_bits(hex_id: u32, hex_pdu: u64) -> Result<Self, anyhow::Error> {
-        let id = Id::<J1939>::try_from_bits(hex_id)?;
-        let pdu = Pdu::<Name>::try_from_bits(hex_pdu)?;
+    pub fn try_from_bits(hex_id: P::Bits, hex_pdu: u64) -> Result<Self, Error> {
+        let id = Id::<P>::try_from_bits(hex_id)?;
+        let pdu = Pdu::<U>::try_from_bits(hex_pdu)?;
 
-        Ok(Self { id, pdu })
+        Ok(Self {
+            id,
+            pdu,
+            kind: FrameKind::Data,
+        })
     }
 
     /// Constructs a new [`Message`] from hexadecimal string representations of its components.
     /// # Errors
     /// - If failed to construct the identifier field from hex
     /// - If failed to construct the pdu field from hex
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let msg_a = Message::<Can2B, Data>::try_from_hex("1ABCDEF0", "0102030405060708").unwrap();
+    /// let msg_b = Message::<Can2B, Data>::try_from_hex("20000000", "0102030405060708");
+    ///
+    /// assert_eq!(0x1ABC_DEF0, msg_a.id().into_bits());
+    /// assert_eq!(0x01, msg_a.pdu().byte_0());
+    /// assert!(msg_b.is_err());
+    /// ```
     #[inline]
-    pub fn try_from_hex(hex_id: &str, hex_pdu: &str) -> Result<Self, anyhow::Error> {
-        let id = Id::<J1939>::try_from_hex(hex_id)?;
-        let pdu = Pdu::<Name>::try_from_hex(hex_pdu)?;
+    pub fn try_from_hex(hex_id: &str, hex_pdu: &str) -> Result<Self, Error> {
+        let id = Id::<P>::try_from_hex(hex_id)?;
+        let pdu = Pdu::<U>::try_from_hex(hex_pdu)?;
 
-        Ok(Self { id, pdu })
+        Ok(Self {
+            id,
+            pdu,
+            kind: FrameKind::Data,
+        })
     }
 
     /// Constructs a new [`Message`] from raw bit representations of its components.
     ///
     /// # Arguments
-    /// - `hex_id`: A `u32` representing the hexadecimal encoded 29-bit identifier.
+    /// - `hex_id`: The integer representing the hexadecimal encoded identifier.
     /// - `hex_pdu`: A `u64` representing the hexadecimal encoded pdu.
     ///
     /// # Returns
     /// A new [`Message`] instance initialized with the decoded components.
     #[inline]
     #[must_use]
-    pub fn from_bits(hex_id: u32, hex_pdu: u64) -> Self {
-        let id = Id::<J1939>::from_bits(hex_id);
-        let pdu = Pdu::<Name>::from_bits(hex_pdu);
+    pub fn from_bits(hex_id: P::Bits, hex_pdu: u64) -> Self {
+        let id = Id::<P>::from_bits(hex_id);
+        let pdu = Pdu::<U>::from_bits(hex_pdu);
 
-        Self { id, pdu }
+        Self {
+            id,
+            pdu,
+            kind: FrameKind::Data,
+        }
     }
 
     /// Constructs a new [`Message`] from hexadecimal string representations of its components.
     ///
     /// # Arguments
-    /// - `hex_id`: A `&str` representing the hexadecimal encoded 29-bit identifier.
+    /// - `hex_id`: A `&str` representing the hexadecimal encoded identifier.
     /// - `hex_pdu`: A `&str` representing the hexadecimal encoded pdu.
     ///
     /// # Returns
@@ -206,47 +310,313 @@ impl Message<J1939, Name> {
     #[inline]
     #[must_use]
     pub fn from_hex(hex_id: &str, hex_pdu: &str) -> Self {
-        let id = Id::<J1939>::from_hex(hex_id);
-        let pdu = Pdu::<Name>::from_hex(hex_pdu);
+        let id = Id::<P>::from_hex(hex_id);
+        let pdu = Pdu::<U>::from_hex(hex_pdu);
 
-        Self { id, pdu }
+        Self {
+            id,
+            pdu,
+            kind: FrameKind::Data,
+        }
     }
+}
 
-    /// Retrieves the 29-bit identifier from the message.
+impl<P> Message<P, ClassicData>
+where
+    P: IsProtocol,
+    Id<P>: Conversion<P::Bits, Error = Error>,
+{
+    /// Constructs a new [`Message`] from hexadecimal string representations of its components.
     ///
-    /// # Returns
-    /// The [`Id<J1939>`] bitfield associated with the message.
+    /// The length of the classical data field is taken from the number of digits in `hex_pdu`.
+    /// # Errors
+    /// - If failed to construct the identifier field from hex
+    /// - If failed to construct the pdu field from hex
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let msg_a = Message::<Can2A, ClassicData>::try_from_hex("7DF", "020100").unwrap();
+    ///
+    /// assert_eq!(3, msg_a.pdu().len());
+    /// ```
     #[inline]
-    #[must_use]
-    pub fn id(&self) -> Id<J1939> {
-        self.id
+    pub fn try_from_hex(hex_id: &str, hex_pdu: &str) -> Result<Self, Error> {
+        let id = Id::<P>::try_from_hex(hex_id)?;
+        let pdu = Pdu::<ClassicData>::try_from_hex(hex_pdu)?;
+
+        Ok(Self {
+            id,
+            pdu,
+            kind: FrameKind::Data,
+        })
     }
+}
 
-    /// Retrieves the pdu from the message.
+impl<P> FromStr for Message<P, ClassicData>
+where
+    P: IsProtocol + Copy,
+    Id<P>: Conversion<P::Bits, Error = Error>,
+{
+    type Err = Error;
+
+    /// Parses a [`Message`] from its `candump` log notation, see the [module](self) documentation.
     ///
-    /// # Returns
-    /// The [`Pdu<Data>`] bitfield associated with the message.
+    /// # Errors
+    /// - [`Error::MalformedFrame`] if the string slice has no `#` separator.
+    /// - [`Error::InvalidDlc`] if the DLC of a remote frame is not a hex digit.
+    /// - If failed to construct the identifier field from hex
+    /// - If failed to construct the pdu field from hex
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let msg_a: Message<Can2A, ClassicData> = "7DF#020100".parse().unwrap();
+    /// let msg_b: Message<Can2A, ClassicData> = "7DF#R3".parse().unwrap();
+    ///
+    /// assert_eq!(3, msg_a.pdu().len());
+    /// assert_eq!(FrameKind::Remote(3), msg_b.kind());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hex_id, body) = s.split_once('#').ok_or(Error::MalformedFrame)?;
+        let id = Id::<P>::try_from_hex(hex_id)?;
+
+        match body {
+            "E" => Ok(Self::error(id)),
+            "O" => Ok(Self::overload(id)),
+            "R" => Self::remote(id, 0),
+            _ => match body.strip_prefix('R') {
+                Some(dlc) => {
+                    let mut digits = dlc.chars();
+                    match (digits.next().and_then(|c| c.to_digit(16)), digits.next()) {
+                        #[allow(clippy::cast_possible_truncation)]
+                        (Some(dlc), None) => Self::remote(id, dlc as u8),
+                        _ => Err(Error::MalformedFrame),
+                    }
+                }
+                None => Ok(Self::from_parts(
+                    id,
+                    Pdu::<ClassicData>::try_from_hex(body)?,
+                )),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Display for Message<P, ClassicData>
+where
+    P: IsProtocol + Copy,
+    Id<P>: Conversion<P::Bits, Error = Error>,
+{
+    /// Formats the [`Message`] in its `candump` log notation, see the [module](self) documentation.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let hex_id = self.id.into_hex();
+
+        match self.kind {
+            FrameKind::Data => write!(f, "{hex_id}#{}", self.pdu.into_hex()),
+            FrameKind::Remote(0) => write!(f, "{hex_id}#R"),
+            FrameKind::Remote(dlc) => write!(f, "{hex_id}#R{dlc:X}"),
+            FrameKind::Error => write!(f, "{hex_id}#E"),
+            FrameKind::Overload => write!(f, "{hex_id}#O"),
+        }
+    }
+}
+
+impl<P> Message<P, FdData>
+where
+    P: IsProtocol,
+    Id<P>: Conversion<P::Bits, Error = Error>,
+{
+    /// Constructs a new CAN FD [`Message`] from hexadecimal string representations of its components.
+    ///
+    /// The BRS and ESI flags of the resulting data field are cleared.
+    /// # Errors
+    /// - If failed to construct the identifier field from hex
+    /// - If failed to construct the pdu field from hex
     #[inline]
-    #[must_use]
-    pub fn pdu(&self) -> Pdu<Name> {
-        self.pdu
+    pub fn try_from_hex(hex_id: &str, hex_pdu: &str) -> Result<Self, Error> {
+        let id = Id::<P>::try_from_hex(hex_id)?;
+        let pdu = Pdu::<FdData>::try_from_hex(hex_pdu)?;
+
+        Ok(Self {
+            id,
+            pdu,
+            kind: FrameKind::Data,
+        })
     }
 }
 
-impl From<Message<J1939, Data>> for Message<J1939, Name> {
-    fn from(value: Message<J1939, Data>) -> Self {
+impl<P: IsProtocol> From<Message<P, Data>> for Message<P, Name> {
+    fn from(value: Message<P, Data>) -> Self {
         Self {
-            id: value.id(),
-            pdu: value.pdu().into(),
+            id: value.id,
+            pdu: value.pdu.into(),
+            kind: value.kind,
         }
     }
 }
 
-impl From<Message<J1939, Name>> for Message<J1939, Data> {
-    fn from(value: Message<J1939, Name>) -> Self {
+impl<P: IsProtocol> From<Message<P, Name>> for Message<P, Data> {
+    fn from(value: Message<P, Name>) -> Self {
         Self {
-            id: value.id(),
-            pdu: value.pdu().into(),
+            id: value.id,
+            pdu: value.pdu.into(),
+            kind: value.kind,
         }
     }
 }
+
+#[cfg(test)]
+mod message_tests {
+    use super::*;
+    use crate::protocol::{
+        can2_a::identifier::{Can2A, IdCan2A},
+        can2_b::identifier::{Can2B, IdCan2B},
+        j1939::identifier::J1939,
+    };
+
+    #[test]
+    fn test_can2a_message() -> Result<(), Error> {
+        let msg_a = Message::<Can2A, Data>::try_from_hex("0F1", "FFFF82DF1AFFFFFF")?;
+        let msg_b = Message::<Can2A, Data>::from_bits(0x0F1, 0xFFFF_82DF_1AFF_FFFF);
+
+        assert_eq!(msg_a, msg_b);
+        assert_eq!(IdCan2A::from_hex("0F1"), msg_a.id());
+        assert_eq!(0x82, msg_a.pdu().byte_2());
+        assert!(Message::<Can2A, Data>::try_from_hex("800", "00").is_err());
+        assert!(Message::<Can2A, Data>::try_from_bits(0x800, 0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_can2b_message() -> Result<(), Error> {
+        let id_a = IdCan2B::try_from_hex("1ABCDEF0")?;
+        let pdu_a = Pdu::<Data>::try_from_hex("FFFF82DF1AFFFFFF")?;
+        let msg_a = Message::<Can2B, Data>::from_parts(id_a, pdu_a);
+
+        assert_eq!((id_a, pdu_a), msg_a.into_parts());
+        assert_eq!(
+            msg_a,
+            Message::<Can2B, Data>::try_from_bits(0x1ABC_DEF0, 0xFFFF_82DF_1AFF_FFFF)?
+        );
+        assert!(Message::<Can2B, Data>::try_from_hex("20000000", "00").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_name_message_from() -> Result<(), Error> {
+        let msg_a = Message::<J1939, Data>::try_from_hex("18EEFF00", "FFFF82DF1AFFFFFF")?;
+        let msg_b = Message::<J1939, Name>::from(msg_a);
+
+        assert_eq!(msg_a.id(), msg_b.id());
+        assert_eq!(msg_a, Message::<J1939, Data>::from(msg_b));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_classic_message() -> Result<(), Error> {
+        let id_a = Id::<J1939>::try_from_hex("18FEF200")?;
+        let msg_a = Message::<J1939, ClassicData>::from_parts(
+            id_a,
+            Pdu::<ClassicData>::try_from_hex("0A0B0C")?,
+        );
+        let msg_b = Message::<J1939, ClassicData>::from_parts(
+            id_a,
+            Pdu::<ClassicData>::try_from_hex("0A0B0C0000000000")?,
+        );
+
+        assert_ne!(msg_a, msg_b);
+        assert_eq!(3, msg_a.pdu().len());
+        assert_eq!(
+            msg_a,
+            Message::<J1939, ClassicData>::try_from_hex("18FEF200", "0A0B0C")?
+        );
+
+        #[cfg(feature = "alloc")]
+        assert_eq!("0A0B0C", msg_a.pdu().into_hex());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame_kind() -> Result<(), Error> {
+        let id_a = IdCan2A::try_from_hex("7DF")?;
+        let msg_a = Message::<Can2A, ClassicData>::remote(id_a, 8)?;
+        let msg_b = Message::<Can2A, ClassicData>::error(IdCan2A::from_bits(0));
+        let msg_c = Message::<Can2A, Data>::overload(IdCan2A::from_bits(0));
+
+        assert_eq!(FrameKind::Remote(8), msg_a.kind());
+        assert!(msg_a.is_remote());
+        assert!(msg_a.pdu().is_empty());
+        assert!(msg_b.is_error());
+        assert!(msg_c.is_overload());
+        assert_eq!(
+            Err(Error::InvalidDlc(16)),
+            Message::<Can2A, ClassicData>::remote(id_a, 16)
+        );
+
+        // An RTR frame is distinct from an empty data frame with the same identifier.
+        let msg_d = Message::<Can2A, ClassicData>::from_parts(id_a, Pdu::<ClassicData>::new());
+        assert!(msg_d.is_data());
+        assert_ne!(Message::<Can2A, ClassicData>::remote(id_a, 0)?, msg_d);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame_str() -> Result<(), Error> {
+        let msg_a: Message<Can2A, ClassicData> = "7DF#020100".parse()?;
+        let msg_b: Message<Can2A, ClassicData> = "7DF#R".parse()?;
+        let msg_c: Message<Can2B, ClassicData> = "18DAF110#RF".parse()?;
+        let msg_d: Message<Can2A, ClassicData> = "000#E".parse()?;
+        let msg_e: Message<Can2A, ClassicData> = "000#O".parse()?;
+
+        assert!(msg_a.is_data());
+        assert_eq!(&[0x02, 0x01, 0x00], msg_a.pdu().as_slice());
+        assert_eq!(FrameKind::Remote(0), msg_b.kind());
+        assert_eq!(FrameKind::Remote(15), msg_c.kind());
+        assert_eq!(FrameKind::Error, msg_d.kind());
+        assert_eq!(FrameKind::Overload, msg_e.kind());
+
+        assert_eq!(
+            Err(Error::MalformedFrame),
+            "7DF020100".parse::<Message<Can2A, ClassicData>>()
+        );
+        assert_eq!(
+            Err(Error::MalformedFrame),
+            "7DF#R10".parse::<Message<Can2A, ClassicData>>()
+        );
+        assert!("800#00".parse::<Message<Can2A, ClassicData>>().is_err());
+
+        #[cfg(feature = "alloc")]
+        {
+            use alloc::string::ToString;
+
+            for frame in ["7DF#020100", "7DF#", "7DF#R", "7DF#R8", "000#E", "000#O"] {
+                let msg: Message<Can2A, ClassicData> = frame.parse()?;
+                assert_eq!(frame, msg.to_string());
+            }
+            assert_eq!("18DAF110#RF", msg_c.to_string());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fd_message() -> Result<(), Error> {
+        let id_a = Id::<J1939>::try_from_hex("18FEF200")?;
+        let pdu_a = Pdu::<FdData>::try_from_slice(&[0xFF; 20])?.with_brs(true);
+        let msg_a = Message::<J1939, FdData>::from_parts(id_a, pdu_a);
+
+        assert_eq!(id_a, msg_a.id());
+        assert_eq!(20, msg_a.pdu().len());
+        assert!(msg_a.pdu().brs());
+        assert_eq!((id_a, pdu_a), msg_a.into_parts());
+
+        Ok(())
+    }
+}
@@ -13,7 +13,7 @@ if_alloc! {
 
 use bitfield_struct::bitfield;
 
-use crate::conversion::Conversion;
+use crate::{conversion::Conversion, error::Error};
 
 /// Marks a type, associating it with a protocol data unit (PDU)
 pub trait IsDataUnit {}
@@ -96,15 +96,144 @@ pub struct Name {
     identity_number_bits: u32,
 }
 
+/// Represents the variable-length data field of a classical CAN frame.
+///
+/// Unlike [`Data`], which always holds 8 bytes, a classical data field records its Data Length
+/// Code (DLC) so that a 3-byte frame is distinct from an 8-byte frame whose upper bytes are zero.
+///
+/// Bytes beyond the length of the data field are always zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ClassicData {
+    len: u8,
+    bytes: [u8; 8],
+}
+
+/// Represents the variable-length data field of a CAN FD frame.
+///
+/// A CAN FD data field holds between 0 and 64 bytes, restricted to the lengths expressible by a
+/// 4-bit Data Length Code (DLC): 0 through 8, 12, 16, 20, 24, 32, 48, and 64. The bit-rate-switch
+/// (BRS) and error-state-indicator (ESI) flags of the frame are carried alongside the bytes.
+///
+/// Bytes beyond the length of the data field are always zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FdData {
+    len: u8,
+    bytes: [u8; 64],
+    brs: bool,
+    esi: bool,
+}
+
 impl IsDataUnit for Data {}
 impl IsDataUnit for Name {}
+impl IsDataUnit for ClassicData {}
+impl IsDataUnit for FdData {}
+
+/// Maps each 4-bit Data Length Code (DLC) to the CAN FD payload length in bytes.
+const FD_DLC_LENGTHS: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 16, 20, 24, 32, 48, 64];
+
+/// Converts a 4-bit Data Length Code (DLC) into a CAN FD payload length in bytes.
+///
+/// # Returns
+/// - `Some(len)` if `dlc` is between 0 and 15 inclusive.
+/// - `None` otherwise.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::payload::dlc_to_len;
+/// assert_eq!(Some(8), dlc_to_len(8));
+/// assert_eq!(Some(64), dlc_to_len(15));
+/// assert_eq!(None, dlc_to_len(16));
+/// ```
+#[inline]
+#[must_use]
+pub const fn dlc_to_len(dlc: u8) -> Option<usize> {
+    if dlc < 16 {
+        Some(FD_DLC_LENGTHS[dlc as usize] as usize)
+    } else {
+        None
+    }
+}
+
+/// Converts a CAN FD payload length in bytes into its 4-bit Data Length Code (DLC).
+///
+/// # Returns
+/// - `Some(dlc)` if `len` is a legal CAN FD payload length.
+/// - `None` otherwise.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::payload::len_to_dlc;
+/// assert_eq!(Some(8), len_to_dlc(8));
+/// assert_eq!(Some(13), len_to_dlc(32));
+/// assert_eq!(None, len_to_dlc(9));
+/// ```
+#[inline]
+#[must_use]
+pub const fn len_to_dlc(len: usize) -> Option<u8> {
+    let mut dlc = 0;
+    while dlc < FD_DLC_LENGTHS.len() {
+        if FD_DLC_LENGTHS[dlc] as usize == len {
+            #[allow(clippy::cast_possible_truncation)]
+            return Some(dlc as u8);
+        }
+        dlc += 1;
+    }
+    None
+}
+
+/// Decodes a base-16 (hex) string slice into `buf`, returning the number of bytes written.
+///
+/// # Errors
+/// - [`Error::OddHexLength`] if the string slice does not contain a whole number of bytes.
+/// - [`Error::InvalidDataLength`] if the decoded bytes do not fit in `buf`.
+/// - [`Error::InvalidHexDigit`] if the string slice contains a non-hex character.
+fn decode_hex(hex_str: &str, buf: &mut [u8]) -> Result<usize, Error> {
+    let digits = hex_str.chars().count();
+    if digits % 2 != 0 {
+        return Err(Error::OddHexLength(digits));
+    }
+    if digits / 2 > buf.len() {
+        return Err(Error::InvalidDataLength(digits / 2));
+    }
+
+    let mut chars = hex_str.chars();
+    for byte in buf.iter_mut().take(digits / 2) {
+        let mut value = 0;
+        for c in chars.by_ref().take(2) {
+            let nibble = c.to_digit(16).ok_or(Error::InvalidHexDigit(c))?;
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                value = (value << 4) | nibble as u8;
+            }
+        }
+        *byte = value;
+    }
+
+    Ok(digits / 2)
+}
+
+/// Encodes `bytes` into a base-16 (hex) [`String`], two digits per byte.
+/// # Requires
+/// - `alloc`
+#[cfg(feature = "alloc")]
+fn encode_hex(bytes: &[u8]) -> String {
+    use core::fmt::Write;
+
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        // Writing into a `String` is infallible.
+        let _ = write!(hex, "{byte:02X}");
+    }
+
+    hex
+}
 
 /// Represents a Protocol Data Unit (PDU) in the context of Controller Area Network (CAN).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Pdu<U: IsDataUnit>(pub(crate) U);
 
 impl Conversion<u64> for Pdu<Data> {
-    type Error = anyhow::Error;
+    type Error = Error;
 
     /// Creates a new [`Data`] bitfield from a 64-bit integer.
     fn from_bits(bits: u64) -> Self {
@@ -129,7 +258,7 @@ impl Conversion<u64> for Pdu<Data> {
     /// # Errors
     /// - If failed to parse input hexadecimal string slice.
     fn try_from_hex(hex_str: &str) -> Result<Self, Self::Error> {
-        let bits = u64::from_str_radix(hex_str, 16).map_err(anyhow::Error::msg)?;
+        let bits = u64::from_str_radix(hex_str, 16)?;
 
         Ok(Self(Data(bits)))
     }
@@ -148,6 +277,12 @@ impl Conversion<u64> for Pdu<Data> {
     }
 }
 
+impl Default for Pdu<Data> {
+    fn default() -> Self {
+        Self::from_bits(0)
+    }
+}
+
 impl Pdu<Data> {
     /// Retrieve byte 0.
     #[must_use]
@@ -229,7 +364,7 @@ impl Pdu<Data> {
 }
 
 impl Conversion<u64> for Pdu<Name> {
-    type Error = anyhow::Error;
+    type Error = Error;
 
     /// Creates a new [`Name`] bitfield from a 64-bit integer.
     fn from_bits(bits: u64) -> Self {
@@ -255,7 +390,7 @@ impl Conversion<u64> for Pdu<Name> {
     /// - If invalid encoding of provided Base16 string
     /// - If insufficient output buffer length
     fn try_from_hex(hex_str: &str) -> Result<Self, Self::Error> {
-        let bits = u64::from_str_radix(hex_str, 16).map_err(anyhow::Error::msg)?;
+        let bits = u64::from_str_radix(hex_str, 16)?;
 
         Ok(Self(Name(bits)))
     }
@@ -274,6 +409,12 @@ impl Conversion<u64> for Pdu<Name> {
     }
 }
 
+impl Default for Pdu<Name> {
+    fn default() -> Self {
+        Self::from_bits(0)
+    }
+}
+
 impl Pdu<Name> {
     /// Indicates whether or not the ECU/CA can negotiate an address (true = yes; false = no).
     #[must_use]
@@ -340,6 +481,521 @@ impl Pdu<Name> {
     pub const fn identity_number(&self) -> u32 {
         self.0.identity_number_bits()
     }
+
+    /// Returns a copy of the NAME with the given arbitrary address capability.
+    #[must_use]
+    pub const fn with_arbitrary_address(self, arbitrary_address: bool) -> Self {
+        Self(self.0.with_arbitrary_address_bits(arbitrary_address))
+    }
+
+    /// Returns a copy of the NAME with the given 3-bit industry group.
+    ///
+    /// # Errors
+    /// - [`Error::FieldOutOfRange`] if the industry group exceeds 7.
+    pub fn with_industry_group(self, industry_group: u8) -> Result<Self, Error> {
+        Error::check_field("industry_group", industry_group.into(), 0x7)?;
+
+        Ok(Self(self.0.with_industry_group_bits(industry_group)))
+    }
+
+    /// Returns a copy of the NAME with the given 4-bit vehicle system instance.
+    ///
+    /// # Errors
+    /// - [`Error::FieldOutOfRange`] if the vehicle system instance exceeds 15.
+    pub fn with_vehicle_system_instance(self, vehicle_system_instance: u8) -> Result<Self, Error> {
+        Error::check_field(
+            "vehicle_system_instance",
+            vehicle_system_instance.into(),
+            0xF,
+        )?;
+
+        Ok(Self(self.0.with_vehicle_system_instance_bits(
+            vehicle_system_instance,
+        )))
+    }
+
+    /// Returns a copy of the NAME with the given 7-bit vehicle system.
+    ///
+    /// # Errors
+    /// - [`Error::FieldOutOfRange`] if the vehicle system exceeds 127.
+    pub fn with_vehicle_system(self, vehicle_system: u8) -> Result<Self, Error> {
+        Error::check_field("vehicle_system", vehicle_system.into(), 0x7F)?;
+
+        Ok(Self(self.0.with_vehicle_system_bits(vehicle_system)))
+    }
+
+    /// Returns a copy of the NAME with the given 8-bit function.
+    #[must_use]
+    pub const fn with_function(self, function: u8) -> Self {
+        Self(self.0.with_function_bits(function))
+    }
+
+    /// Returns a copy of the NAME with the given 5-bit function instance.
+    ///
+    /// # Errors
+    /// - [`Error::FieldOutOfRange`] if the function instance exceeds 31.
+    pub fn with_function_instance(self, function_instance: u8) -> Result<Self, Error> {
+        Error::check_field("function_instance", function_instance.into(), 0x1F)?;
+
+        Ok(Self(self.0.with_function_instance_bits(function_instance)))
+    }
+
+    /// Returns a copy of the NAME with the given 3-bit ECU instance.
+    ///
+    /// # Errors
+    /// - [`Error::FieldOutOfRange`] if the ECU instance exceeds 7.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let name_a = Pdu::<Name>::from_hex("FFFF82DF1AFFFFFF");
+    ///
+    /// assert_eq!(2, name_a.with_ecu_instance(2).unwrap().ecu_instance());
+    /// assert!(name_a.with_ecu_instance(8).is_err());
+    /// ```
+    pub fn with_ecu_instance(self, ecu_instance: u8) -> Result<Self, Error> {
+        Error::check_field("ecu_instance", ecu_instance.into(), 0x7)?;
+
+        Ok(Self(self.0.with_ecu_instance_bits(ecu_instance)))
+    }
+
+    /// Returns a copy of the NAME with the given 11-bit manufacturer code.
+    ///
+    /// # Errors
+    /// - [`Error::FieldOutOfRange`] if the manufacturer code exceeds 2047.
+    pub fn with_manufacturer_code(self, manufacturer_code: u16) -> Result<Self, Error> {
+        Error::check_field("manufacturer_code", manufacturer_code.into(), 0x7FF)?;
+
+        Ok(Self(self.0.with_manufacturer_code_bits(manufacturer_code)))
+    }
+
+    /// Returns a copy of the NAME with the given 21-bit identity number.
+    ///
+    /// # Errors
+    /// - [`Error::FieldOutOfRange`] if the identity number exceeds 2097151.
+    pub fn with_identity_number(self, identity_number: u32) -> Result<Self, Error> {
+        Error::check_field("identity_number", identity_number, 0x1F_FFFF)?;
+
+        Ok(Self(self.0.with_identity_number_bits(identity_number)))
+    }
+
+    /// Returns `true` if this NAME wins address claim arbitration against `other`.
+    ///
+    /// When two controller applications claim the same address, SAE J1939-81 gives the address
+    /// to the one with the lower NAME, comparing the 64-bit NAMEs as unsigned integers. The
+    /// arbitrary address bit is the most significant bit, so a NAME that cannot negotiate an
+    /// address takes precedence over one that can. Identical NAMEs do not win against each other.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let name_a = Pdu::<Name>::from_hex("0000000000000001");
+    /// let name_b = Pdu::<Name>::from_hex("8000000000000000");
+    ///
+    /// assert!(name_a.wins_arbitration_against(&name_b));
+    /// assert!(!name_b.wins_arbitration_against(&name_a));
+    /// assert!(!name_a.wins_arbitration_against(&name_a));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn wins_arbitration_against(&self, other: &Self) -> bool {
+        self.0 .0 < other.0 .0
+    }
+}
+
+/// Builds a J1939 NAME from its fields, checking that each one fits its bit width.
+///
+/// # Defaults
+/// Every field defaults to 0, and `arbitrary_address` to `false`.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// # fn main() -> Result<(), can_types::error::Error> {
+/// let name_a = NameBuilder::new()
+///     .arbitrary_address(true)
+///     .industry_group(1)
+///     .function(0)
+///     .manufacturer_code(0x122)
+///     .identity_number(0xB0309)
+///     .build()?;
+///
+/// assert_eq!(Pdu::<Name>::from_hex("90000000244B0309"), name_a);
+/// assert!(NameBuilder::new().industry_group(8).build().is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NameBuilder {
+    arbitrary_address: bool,
+    industry_group: u8,
+    vehicle_system_instance: u8,
+    vehicle_system: u8,
+    function: u8,
+    function_instance: u8,
+    ecu_instance: u8,
+    manufacturer_code: u16,
+    identity_number: u32,
+}
+
+impl NameBuilder {
+    /// Creates a new builder with every field set to 0.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            arbitrary_address: false,
+            industry_group: 0,
+            vehicle_system_instance: 0,
+            vehicle_system: 0,
+            function: 0,
+            function_instance: 0,
+            ecu_instance: 0,
+            manufacturer_code: 0,
+            identity_number: 0,
+        }
+    }
+
+    /// Sets whether the ECU/CA can negotiate an address.
+    #[inline]
+    #[must_use]
+    pub const fn arbitrary_address(mut self, arbitrary_address: bool) -> Self {
+        self.arbitrary_address = arbitrary_address;
+        self
+    }
+
+    /// Sets the 3-bit industry group (0-7).
+    #[inline]
+    #[must_use]
+    pub const fn industry_group(mut self, industry_group: u8) -> Self {
+        self.industry_group = industry_group;
+        self
+    }
+
+    /// Sets the 4-bit vehicle system instance (0-15).
+    #[inline]
+    #[must_use]
+    pub const fn vehicle_system_instance(mut self, vehicle_system_instance: u8) -> Self {
+        self.vehicle_system_instance = vehicle_system_instance;
+        self
+    }
+
+    /// Sets the 7-bit vehicle system (0-127).
+    #[inline]
+    #[must_use]
+    pub const fn vehicle_system(mut self, vehicle_system: u8) -> Self {
+        self.vehicle_system = vehicle_system;
+        self
+    }
+
+    /// Sets the 8-bit function (0-255).
+    #[inline]
+    #[must_use]
+    pub const fn function(mut self, function: u8) -> Self {
+        self.function = function;
+        self
+    }
+
+    /// Sets the 5-bit function instance (0-31).
+    #[inline]
+    #[must_use]
+    pub const fn function_instance(mut self, function_instance: u8) -> Self {
+        self.function_instance = function_instance;
+        self
+    }
+
+    /// Sets the 3-bit ECU instance (0-7).
+    #[inline]
+    #[must_use]
+    pub const fn ecu_instance(mut self, ecu_instance: u8) -> Self {
+        self.ecu_instance = ecu_instance;
+        self
+    }
+
+    /// Sets the 11-bit manufacturer code (0-2047).
+    #[inline]
+    #[must_use]
+    pub const fn manufacturer_code(mut self, manufacturer_code: u16) -> Self {
+        self.manufacturer_code = manufacturer_code;
+        self
+    }
+
+    /// Sets the 21-bit identity number (0-2097151).
+    #[inline]
+    #[must_use]
+    pub const fn identity_number(mut self, identity_number: u32) -> Self {
+        self.identity_number = identity_number;
+        self
+    }
+
+    /// Builds the NAME. The reserved bit is always 0.
+    ///
+    /// # Errors
+    /// - [`Error::FieldOutOfRange`] if a field exceeds its bit width, naming the first such field.
+    pub fn build(self) -> Result<Pdu<Name>, Error> {
+        Pdu::<Name>::default()
+            .with_arbitrary_address(self.arbitrary_address)
+            .with_industry_group(self.industry_group)?
+            .with_vehicle_system_instance(self.vehicle_system_instance)?
+            .with_vehicle_system(self.vehicle_system)?
+            .with_function(self.function)
+            .with_function_instance(self.function_instance)?
+            .with_ecu_instance(self.ecu_instance)?
+            .with_manufacturer_code(self.manufacturer_code)?
+            .with_identity_number(self.identity_number)
+    }
+}
+
+impl Pdu<ClassicData> {
+    /// Creates a new, empty classical data field.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(ClassicData {
+            len: 0,
+            bytes: [0; 8],
+        })
+    }
+
+    /// Creates a new classical data field from a slice of up to 8 bytes.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidDataLength`] if the slice is longer than 8 bytes.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let data_a = Pdu::<ClassicData>::try_from_slice(&[0x01, 0x02, 0x03]).unwrap();
+    /// let data_b = Pdu::<ClassicData>::try_from_slice(&[0x01, 0x02, 0x03, 0x00]).unwrap();
+    ///
+    /// assert_eq!(3, data_a.len());
+    /// assert_ne!(data_a, data_b);
+    /// ```
+    pub fn try_from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() > 8 {
+            return Err(Error::InvalidDataLength(bytes.len()));
+        }
+        let mut data = Self::new();
+        data.0.bytes[..bytes.len()].copy_from_slice(bytes);
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            data.0.len = bytes.len() as u8;
+        }
+
+        Ok(data)
+    }
+
+    /// Creates a new classical data field from a base-16 (hex) string slice, two digits per byte.
+    ///
+    /// The length of the data field is taken from the number of digits, so `"0A0B0C"` yields a
+    /// 3-byte data field.
+    ///
+    /// # Errors
+    /// - [`Error::OddHexLength`] if the string slice does not contain a whole number of bytes.
+    /// - [`Error::InvalidHexDigit`] if the string slice contains a non-hex character.
+    /// - [`Error::InvalidDataLength`] if the string slice encodes more than 8 bytes.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let data_a = Pdu::<ClassicData>::try_from_hex("0A0B0C").unwrap();
+    ///
+    /// assert_eq!(&[0x0A, 0x0B, 0x0C], data_a.as_slice());
+    /// ```
+    pub fn try_from_hex(hex_str: &str) -> Result<Self, Error> {
+        let mut buf = [0; 8];
+        let len = decode_hex(hex_str, &mut buf)?;
+
+        Self::try_from_slice(&buf[..len])
+    }
+
+    /// Creates a new base-16 (hex) [`String`] from the bytes of the classical data field.
+    ///
+    /// Only the bytes within the length of the data field are encoded.
+    /// # Requires
+    /// - `alloc`
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn into_hex(self) -> String {
+        encode_hex(self.as_slice())
+    }
+
+    /// Returns the number of bytes in the data field.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len as usize
+    }
+
+    /// Returns `true` if the data field contains no bytes.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.len == 0
+    }
+
+    /// Returns the Data Length Code (DLC) of the data field, between 0 and 8 inclusive.
+    #[inline]
+    #[must_use]
+    pub const fn dlc(&self) -> u8 {
+        self.0.len
+    }
+
+    /// Returns the bytes of the data field.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0.bytes[..self.len()]
+    }
+}
+
+impl Default for Pdu<ClassicData> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pdu<FdData> {
+    /// Creates a new, empty CAN FD data field with the BRS and ESI flags cleared.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(FdData {
+            len: 0,
+            bytes: [0; 64],
+            brs: false,
+            esi: false,
+        })
+    }
+
+    /// Creates a new CAN FD data field from a slice of bytes.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidDataLength`] if the slice length is not a legal CAN FD payload length.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let fd_a = Pdu::<FdData>::try_from_slice(&[0xAA; 12]).unwrap();
+    /// let fd_b = Pdu::<FdData>::try_from_slice(&[0xAA; 9]);
+    ///
+    /// assert_eq!(12, fd_a.len());
+    /// assert_eq!(9, fd_a.dlc());
+    /// assert!(fd_b.is_err());
+    /// ```
+    pub fn try_from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        if len_to_dlc(bytes.len()).is_none() {
+            return Err(Error::InvalidDataLength(bytes.len()));
+        }
+        let mut fd = Self::new();
+        fd.0.bytes[..bytes.len()].copy_from_slice(bytes);
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            fd.0.len = bytes.len() as u8;
+        }
+
+        Ok(fd)
+    }
+
+    /// Creates a new CAN FD data field from a base-16 (hex) string slice, two digits per byte.
+    ///
+    /// # Errors
+    /// - [`Error::OddHexLength`] if the string slice does not contain a whole number of bytes.
+    /// - [`Error::InvalidHexDigit`] if the string slice contains a non-hex character.
+    /// - [`Error::InvalidDataLength`] if the number of bytes is not a legal CAN FD payload length.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let fd_a = Pdu::<FdData>::try_from_hex("0102030405060708090A0B0C").unwrap();
+    ///
+    /// assert_eq!(12, fd_a.len());
+    /// assert_eq!(0x0C, fd_a.as_slice()[11]);
+    /// ```
+    pub fn try_from_hex(hex_str: &str) -> Result<Self, Error> {
+        let mut buf = [0; 64];
+        let len = decode_hex(hex_str, &mut buf)?;
+
+        Self::try_from_slice(&buf[..len])
+    }
+
+    /// Creates a new base-16 (hex) [`String`] from the bytes of the CAN FD data field.
+    /// # Requires
+    /// - `alloc`
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn into_hex(self) -> String {
+        encode_hex(self.as_slice())
+    }
+
+    /// Returns the number of bytes in the data field.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len as usize
+    }
+
+    /// Returns `true` if the data field contains no bytes.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.len == 0
+    }
+
+    /// Returns the 4-bit Data Length Code (DLC) of the data field.
+    #[inline]
+    #[must_use]
+    pub const fn dlc(&self) -> u8 {
+        match len_to_dlc(self.len()) {
+            Some(dlc) => dlc,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the bytes of the data field.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0.bytes[..self.len()]
+    }
+
+    /// Returns the bit-rate-switch (BRS) flag.
+    ///
+    /// When set, the data phase of the frame is transmitted at the higher data bit rate.
+    #[inline]
+    #[must_use]
+    pub const fn brs(&self) -> bool {
+        self.0.brs
+    }
+
+    /// Returns the error-state-indicator (ESI) flag.
+    ///
+    /// When set, the transmitting node is error passive.
+    #[inline]
+    #[must_use]
+    pub const fn esi(&self) -> bool {
+        self.0.esi
+    }
+
+    /// Returns a copy of the data field with the bit-rate-switch (BRS) flag set to `brs`.
+    #[inline]
+    #[must_use]
+    pub const fn with_brs(mut self, brs: bool) -> Self {
+        self.0.brs = brs;
+        self
+    }
+
+    /// Returns a copy of the data field with the error-state-indicator (ESI) flag set to `esi`.
+    #[inline]
+    #[must_use]
+    pub const fn with_esi(mut self, esi: bool) -> Self {
+        self.0.esi = esi;
+        self
+    }
+}
+
+impl Default for Pdu<FdData> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
@@ -347,7 +1003,7 @@ mod data_tests {
     use super::*;
 
     #[test]
-    fn test_data_bitfield() -> Result<(), anyhow::Error> {
+    fn test_data_bitfield() -> Result<(), Error> {
         let data_a = Pdu::<Data>::from_hex("FFFF82DF1AFFFFFF");
         let be_bytes_a: [u8; 8] = [0xFF, 0xFF, 0x82, 0xDF, 0x1A, 0xFF, 0xFF, 0xFF];
         let le_bytes_a: [u8; 8] = [0xFF, 0xFF, 0xFF, 0x1A, 0xDF, 0x82, 0xFF, 0xFF];
@@ -384,4 +1040,181 @@ mod data_tests {
 
         assert_eq!(bytes_a, name_a_bytes);
     }
+
+    #[test]
+    fn test_name_setters() -> Result<(), Error> {
+        let name_a = Pdu::<Name>::default()
+            .with_arbitrary_address(true)
+            .with_industry_group(0)?
+            .with_vehicle_system_instance(0x5)?
+            .with_vehicle_system(0x6)?
+            .with_function(0x5)
+            .with_function_instance(0x2)?
+            .with_ecu_instance(0x1)?
+            .with_manufacturer_code(0x122)?
+            .with_identity_number(0xB0309)?;
+
+        let bytes_a: [u8; 8] = [0x09, 0x03, 0x4B, 0x24, 0x11, 0x05, 0x0C, 0x85];
+
+        assert_eq!(bytes_a, name_a.into_bits().to_le_bytes());
+        assert_eq!(
+            Err(Error::FieldOutOfRange {
+                field: "industry_group",
+                value: 8,
+                max: 7
+            }),
+            name_a.with_industry_group(8)
+        );
+        assert!(name_a.with_vehicle_system_instance(16).is_err());
+        assert!(name_a.with_vehicle_system(128).is_err());
+        assert!(name_a.with_function_instance(32).is_err());
+        assert!(name_a.with_ecu_instance(8).is_err());
+        assert!(name_a.with_manufacturer_code(0x800).is_err());
+        assert!(name_a.with_identity_number(0x20_0000).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_name_builder() -> Result<(), Error> {
+        let name_a = NameBuilder::new()
+            .arbitrary_address(true)
+            .industry_group(0)
+            .vehicle_system_instance(0x5)
+            .vehicle_system(0x6)
+            .function(0x5)
+            .function_instance(0x2)
+            .ecu_instance(0x1)
+            .manufacturer_code(0x122)
+            .identity_number(0xB0309)
+            .build()?;
+
+        let bytes_a: [u8; 8] = [0x09, 0x03, 0x4B, 0x24, 0x11, 0x05, 0x0C, 0x85];
+
+        assert_eq!(bytes_a, name_a.into_bits().to_le_bytes());
+        assert_eq!(Ok(Pdu::<Name>::default()), NameBuilder::new().build());
+        assert_eq!(
+            Err(Error::FieldOutOfRange {
+                field: "manufacturer_code",
+                value: 0x800,
+                max: 0x7FF
+            }),
+            NameBuilder::new().manufacturer_code(0x800).build()
+        );
+        assert!(NameBuilder::new().vehicle_system(128).build().is_err());
+        assert!(NameBuilder::new()
+            .identity_number(0x20_0000)
+            .build()
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_name_arbitration() -> Result<(), Error> {
+        let name_a = NameBuilder::new().identity_number(1).build()?;
+        let name_b = NameBuilder::new().identity_number(2).build()?;
+        let name_c = NameBuilder::new().arbitrary_address(true).build()?;
+        let name_d = NameBuilder::new().industry_group(1).build()?;
+
+        // The lower NAME wins, whichever field makes the difference.
+        assert!(name_a.wins_arbitration_against(&name_b));
+        assert!(!name_b.wins_arbitration_against(&name_a));
+        assert!(name_b.wins_arbitration_against(&name_d));
+        assert!(name_d.wins_arbitration_against(&name_c));
+        assert!(!name_a.wins_arbitration_against(&name_a));
+
+        // The derived ordering agrees with arbitration.
+        assert_eq!(name_a < name_b, name_a.wins_arbitration_against(&name_b));
+        assert_eq!(name_c < name_d, name_c.wins_arbitration_against(&name_d));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dlc_len() {
+        for dlc in 0..16 {
+            let len = dlc_to_len(dlc).unwrap();
+
+            assert_eq!(Some(dlc), len_to_dlc(len));
+        }
+
+        assert_eq!(None, dlc_to_len(16));
+        assert_eq!(None, len_to_dlc(13));
+        assert_eq!(None, len_to_dlc(65));
+    }
+
+    #[test]
+    fn test_classic_data() -> Result<(), Error> {
+        let data_a = Pdu::<ClassicData>::try_from_hex("0A0B0C")?;
+        let data_b = Pdu::<ClassicData>::try_from_hex("0A0B0C0000000000")?;
+
+        assert_eq!(3, data_a.len());
+        assert_eq!(3, data_a.dlc());
+        assert_eq!(&[0x0A, 0x0B, 0x0C], data_a.as_slice());
+        assert_eq!(8, data_b.len());
+        assert_ne!(data_a, data_b);
+        assert!(Pdu::<ClassicData>::try_from_hex("")?.is_empty());
+        assert_eq!(
+            Err(Error::InvalidDataLength(9)),
+            Pdu::<ClassicData>::try_from_slice(&[0; 9])
+        );
+
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!("0A0B0C", data_a.into_hex());
+            assert_eq!("0A0B0C0000000000", data_b.into_hex());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fd_data() -> Result<(), Error> {
+        let fd_a = Pdu::<FdData>::try_from_slice(&[0x11; 48])?
+            .with_brs(true)
+            .with_esi(false);
+
+        assert_eq!(48, fd_a.len());
+        assert_eq!(14, fd_a.dlc());
+        assert_eq!(&[0x11; 48], fd_a.as_slice());
+        assert!(fd_a.brs());
+        assert!(!fd_a.esi());
+
+        let fd_b = Pdu::<FdData>::try_from_slice(&[])?;
+
+        assert!(fd_b.is_empty());
+        assert_eq!(0, fd_b.dlc());
+        assert_eq!(
+            Err(Error::InvalidDataLength(65)),
+            Pdu::<FdData>::try_from_slice(&[0; 65])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fd_data_hex() -> Result<(), Error> {
+        let fd_a = Pdu::<FdData>::try_from_hex("00112233445566778899AABB")?;
+
+        assert_eq!(12, fd_a.len());
+        assert_eq!(0xBB, fd_a.as_slice()[11]);
+        assert_eq!(
+            Err(Error::OddHexLength(3)),
+            Pdu::<FdData>::try_from_hex("001")
+        );
+        assert_eq!(
+            Err(Error::InvalidHexDigit('G')),
+            Pdu::<FdData>::try_from_hex("0G")
+        );
+        assert_eq!(
+            Err(Error::InvalidDataLength(9)),
+            Pdu::<FdData>::try_from_hex("001122334455667788")
+        );
+
+        #[cfg(feature = "alloc")]
+        assert_eq!("00112233445566778899AABB", fd_a.into_hex());
+
+        Ok(())
+    }
 }
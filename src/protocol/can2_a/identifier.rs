@@ -15,6 +15,7 @@ use bitfield_struct::bitfield;
 
 use crate::{
     conversion::Conversion,
+    error::Error,
     identifier::{Id, IsProtocol},
 };
 
@@ -35,7 +36,9 @@ pub struct Can2A {
     id_bits: u16,
 }
 
-impl IsProtocol for Can2A {}
+impl IsProtocol for Can2A {
+    type Bits = u16;
+}
 
 pub type IdCan2A = Id<Can2A>;
 
@@ -49,7 +52,7 @@ impl IdCan2A {
 }
 
 impl Conversion<u16> for IdCan2A {
-    type Error = anyhow::Error;
+    type Error = Error;
 
     /// Creates a new 11-bit standard identifier from a 16-bit integer.
     ///
@@ -100,10 +103,10 @@ impl Conversion<u16> for IdCan2A {
     #[inline]
     fn try_from_bits(bits: u16) -> Result<Self, Self::Error> {
         if bits > 0x7FF {
-            return Err(anyhow::anyhow!(
-                "Identifier bits out of range! Valid range is 0..2047 - got {}",
-                bits
-            ));
+            return Err(Error::IdBitsOutOfRange {
+                bits: bits.into(),
+                max: 0x7FF,
+            });
         }
         let id_bitfield = Can2A(bits);
 
@@ -126,12 +129,12 @@ impl Conversion<u16> for IdCan2A {
     /// ```
     #[inline]
     fn try_from_hex(hex_str: &str) -> Result<Self, Self::Error> {
-        let bits = u16::from_str_radix(hex_str, 16).map_err(anyhow::Error::msg)?;
+        let bits = u16::from_str_radix(hex_str, 16)?;
         if bits > 0x7FF {
-            return Err(anyhow::anyhow!(
-                "Identifier bits out of range! Valid range is 0x000..0x7FF - got {:#03X}",
-                bits
-            ));
+            return Err(Error::IdBitsOutOfRange {
+                bits: bits.into(),
+                max: 0x7FF,
+            });
         }
         let id_bitfield = Can2A(bits);
 
@@ -15,6 +15,7 @@ use bitfield_struct::bitfield;
 
 use crate::{
     conversion::Conversion,
+    error::Error,
     identifier::{Id, IsProtocol},
 };
 
@@ -35,7 +36,9 @@ pub struct Can2B {
     id_bits: u32,
 }
 
-impl IsProtocol for Can2B {}
+impl IsProtocol for Can2B {
+    type Bits = u32;
+}
 
 pub type IdCan2B = Id<Can2B>;
 
@@ -49,7 +52,7 @@ impl IdCan2B {
 }
 
 impl Conversion<u32> for IdCan2B {
-    type Error = anyhow::Error;
+    type Error = Error;
 
     /// Creates a new 29-bit extended identifier from a 16-bit integer.
     ///
@@ -101,10 +104,10 @@ impl Conversion<u32> for IdCan2B {
     #[inline]
     fn try_from_bits(bits: u32) -> Result<Self, Self::Error> {
         if bits > 0x1FFF_FFFF {
-            return Err(anyhow::anyhow!(
-                "Identifier bits out of range! Valid range is 0..536870911 - got {}",
-                bits
-            ));
+            return Err(Error::IdBitsOutOfRange {
+                bits,
+                max: 0x1FFF_FFFF,
+            });
         }
         let id_bitfield = Can2B(bits);
 
@@ -128,12 +131,12 @@ impl Conversion<u32> for IdCan2B {
     /// ```
     #[inline]
     fn try_from_hex(hex_str: &str) -> Result<Self, Self::Error> {
-        let bits = u32::from_str_radix(hex_str, 16).map_err(anyhow::Error::msg)?;
+        let bits = u32::from_str_radix(hex_str, 16)?;
         if bits > 0x1FFF_FFFF {
-            return Err(anyhow::anyhow!(
-                "Identifier bits out of range! Valid range is 0x0000_0000..0x1FFF_FFFF - got {:#08X}",
-                bits
-            ));
+            return Err(Error::IdBitsOutOfRange {
+                bits,
+                max: 0x1FFF_FFFF,
+            });
         }
         let id_bitfield = Can2B(bits);
 
@@ -8,13 +8,25 @@
 //! # Vehicle Control Units/Modules, Source Adresses, and Destination Addresses
 
 if_alloc! {
-    use crate::alloc::fmt::{Display, Formatter, Result};
+    use crate::alloc::fmt::{self, Display, Formatter};
 }
 
+use core::str::FromStr;
+
+use crate::{error::Error, protocol::j1939::name::IndustryGroup};
+
 /// The [`Addr`] enum represents various types of electronic control units (ECUs) and modules
 /// commonly found in automotive and heavy-duty vehicle networks. Each variant corresponds to a
 /// specific ECU or system component that communicates over the network.
+///
+/// Addresses 128 to 247 are assigned per industry group, so the variants from
+/// [`Addr::TractorEcu`] to [`Addr::PropulsionSensorsGateway4`] are never returned by
+/// `From<u8>`, which assumes the on-highway industry group. They are only returned by
+/// [`Addr::from_industry_group`], [`SourceAddr::lookup_in`] and [`DestinationAddr::lookup_in`].
+///
+/// The enum is non-exhaustive: more addresses may be added in later versions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Addr {
     /// Primary Engine Controller | (CPC, ECM)
     PrimaryEngineController,
@@ -184,6 +196,51 @@ pub enum Addr {
     /// Service Tool
     ServiceTool,
 
+    /// Tractor ECU | Agricultural and Forestry (TECU)
+    TractorEcu,
+
+    /// Task Controller | Agricultural and Forestry (TC)
+    TaskController,
+
+    /// Supplemental Engine Control Sensing | Construction
+    SupplementalEngineControlSensing,
+
+    /// Laser Receiver (Mast Right or Blade Right) | Construction
+    LaserReceiverRight,
+
+    /// Land Leveling System Operator Interface | Construction
+    LandLevelingOperatorInterface,
+
+    /// Land Leveling Electric Mast | Construction
+    LandLevelingElectricMast,
+
+    /// Single Land Leveling System Supervisor | Construction
+    LandLevelingSupervisor,
+
+    /// Land Leveling System Display | Construction
+    LandLevelingDisplay,
+
+    /// Laser Tracer | Construction
+    LaserTracer,
+
+    /// Loader Control | Construction
+    LoaderControl,
+
+    /// Laser Receiver (Mast Left or Blade Left) | Construction
+    LaserReceiverLeft,
+
+    /// Propulsion Sensors & Gateway #1 | Marine
+    PropulsionSensorsGateway1,
+
+    /// Propulsion Sensors & Gateway #2 | Marine
+    PropulsionSensorsGateway2,
+
+    /// Propulsion Sensors & Gateway #3 | Marine
+    PropulsionSensorsGateway3,
+
+    /// Propulsion Sensors & Gateway #4 | Marine
+    PropulsionSensorsGateway4,
+
     /// Source Address Request 0
     SourceAddressRequest0,
 
@@ -260,6 +317,300 @@ impl From<u8> for Addr {
     }
 }
 
+/// Every known address, excluding [`Addr::Unknown`].
+static KNOWN_ADDRS: [Addr; 73] = [
+    Addr::PrimaryEngineController,
+    Addr::SecondaryEngineController,
+    Addr::PrimaryTransmissionController,
+    Addr::TransmissionShiftSelector,
+    Addr::Brakes,
+    Addr::Retarder,
+    Addr::CruiseControl,
+    Addr::FuelSystem,
+    Addr::SteeringController,
+    Addr::InstrumentCluster,
+    Addr::ClimateControl1,
+    Addr::Compass,
+    Addr::BodyController,
+    Addr::OffVehicleGateway,
+    Addr::DidVid,
+    Addr::RetarderExhaustEngine1,
+    Addr::HeadwayController,
+    Addr::Suspension,
+    Addr::CabController,
+    Addr::TirePressureController,
+    Addr::LightingControlModule,
+    Addr::ClimateControl2,
+    Addr::ExhaustEmissionController,
+    Addr::AuxiliaryHeater,
+    Addr::ChassisController,
+    Addr::CommunicationsUnit,
+    Addr::Radio,
+    Addr::SafetyRestraintSystem,
+    Addr::AftertreatmentControlModule,
+    Addr::MultiPurposeCamera,
+    Addr::SwitchExpansionModule,
+    Addr::AuxillaryGaugeSwitchPack,
+    Addr::Iteris,
+    Addr::QualcommPeopleNetTranslatorBox,
+    Addr::StandAloneRealTimeClock,
+    Addr::CenterPanel1,
+    Addr::CenterPanel2,
+    Addr::CenterPanel3,
+    Addr::CenterPanel4,
+    Addr::CenterPanel5,
+    Addr::WabcoOnGuardRadar,
+    Addr::SecondaryInstrumentCluster,
+    Addr::OffboardDiagnostics,
+    Addr::Trailer3Bridge,
+    Addr::Trailer2Bridge,
+    Addr::Trailer1Bridge,
+    Addr::SafetyDirectProcessor,
+    Addr::ForwardRoadImageProcessor,
+    Addr::LeftRearDoorPod,
+    Addr::RightRearDoorPod,
+    Addr::DoorController1,
+    Addr::DoorController2,
+    Addr::Tachograph,
+    Addr::HybridSystem,
+    Addr::AuxiliaryPowerUnit,
+    Addr::ServiceTool,
+    Addr::TractorEcu,
+    Addr::TaskController,
+    Addr::SupplementalEngineControlSensing,
+    Addr::LaserReceiverRight,
+    Addr::LandLevelingOperatorInterface,
+    Addr::LandLevelingElectricMast,
+    Addr::LandLevelingSupervisor,
+    Addr::LandLevelingDisplay,
+    Addr::LaserTracer,
+    Addr::LoaderControl,
+    Addr::LaserReceiverLeft,
+    Addr::PropulsionSensorsGateway1,
+    Addr::PropulsionSensorsGateway2,
+    Addr::PropulsionSensorsGateway3,
+    Addr::PropulsionSensorsGateway4,
+    Addr::SourceAddressRequest0,
+    Addr::SourceAddressRequest1,
+];
+
+/// Alternative names accepted when parsing an [`Addr`], after the SAE J1939 preferred names.
+static ADDR_ALIASES: [(&str, Addr); 4] = [
+    ("Engine1", Addr::PrimaryEngineController),
+    ("Engine2", Addr::SecondaryEngineController),
+    ("Transmission1", Addr::PrimaryTransmissionController),
+    ("InstrumentCluster1", Addr::InstrumentCluster),
+];
+
+impl Addr {
+    /// Returns the variant name and the description of the address.
+    #[rustfmt::skip]
+    const fn names(self) -> (&'static str, &'static str) {
+        match self {
+            Addr::PrimaryEngineController => ("PrimaryEngineController", "Primary Engine Controller | (CPC, ECM)"),
+            Addr::SecondaryEngineController => ("SecondaryEngineController", "Secondary Engine Controller | (MCM, ECM #2)"),
+            Addr::PrimaryTransmissionController => ("PrimaryTransmissionController", "Primary Transmission Controller | (TCM)"),
+            Addr::TransmissionShiftSelector => ("TransmissionShiftSelector", "Transmission Shift Selector | (TSS)"),
+            Addr::Brakes => ("Brakes", "Brakes | System Controller (ABS)"),
+            Addr::Retarder => ("Retarder", "Retarder"),
+            Addr::CruiseControl => ("CruiseControl", "Cruise Control | (IPM, PCC)"),
+            Addr::FuelSystem => ("FuelSystem", "Fuel System | Controller (CNG)"),
+            Addr::SteeringController => ("SteeringController", "Steering Controller | (SAS)"),
+            Addr::InstrumentCluster => ("InstrumentCluster", "Instrument Guage Cluster (EGC) | (ICU, RX)"),
+            Addr::ClimateControl1 => ("ClimateControl1", "Climate Control #1 | (FCU)"),
+            Addr::Compass => ("Compass", "Compass"),
+            Addr::BodyController => ("BodyController", "Body Controller | (SSAM, SAM-CAB, BHM)"),
+            Addr::OffVehicleGateway => ("OffVehicleGateway", "Off-Vehicle Gateway | (CGW)"),
+            Addr::DidVid => ("DidVid", "Vehicle Information Display | Driver Information Display"),
+            Addr::RetarderExhaustEngine1 => ("RetarderExhaustEngine1", "Retarder, Exhaust, Engine #1"),
+            Addr::HeadwayController => ("HeadwayController", "Headway Controller | (RDF) | (OnGuard)"),
+            Addr::Suspension => ("Suspension", "Suspension | System Controller (ECAS)"),
+            Addr::CabController => ("CabController", "Cab Controller | Primary (MSF, SHM, ECC)"),
+            Addr::TirePressureController => ("TirePressureController", "Tire Pressure Controller | (TPMS)"),
+            Addr::LightingControlModule => ("LightingControlModule", "Lighting Control Module | (LCM)"),
+            Addr::ClimateControl2 => ("ClimateControl2", "Climate Control #2 | Rear HVAC | (ParkSmart)"),
+            Addr::ExhaustEmissionController => ("ExhaustEmissionController", "Exhaust Emission Controller | (ACM) | (DCU)"),
+            Addr::AuxiliaryHeater => ("AuxiliaryHeater", "Auxiliary Heater | (ACU)"),
+            Addr::ChassisController => ("ChassisController", "Chassis Controller | (CHM, SAM-Chassis)"),
+            Addr::CommunicationsUnit => ("CommunicationsUnit", "Communications Unit | Cellular (CTP, VT)"),
+            Addr::Radio => ("Radio", "Radio"),
+            Addr::SafetyRestraintSystem => ("SafetyRestraintSystem", "Safety Restraint System | Air Bag | (SRS)"),
+            Addr::AftertreatmentControlModule => ("AftertreatmentControlModule", "Aftertreatment Control Module | (ACM)"),
+            Addr::MultiPurposeCamera => ("MultiPurposeCamera", "Multi-Purpose Camera | (MPC)"),
+            Addr::SwitchExpansionModule => ("SwitchExpansionModule", "Switch Expansion Module | (SEM #1)"),
+            Addr::AuxillaryGaugeSwitchPack => ("AuxillaryGaugeSwitchPack", "Auxiliary Gauge Switch Pack | (AGSP3)"),
+            Addr::Iteris => ("Iteris", "Iteris"),
+            Addr::QualcommPeopleNetTranslatorBox => ("QualcommPeopleNetTranslatorBox", "Qualcomm - PeopleNet Translator Box"),
+            Addr::StandAloneRealTimeClock => ("StandAloneRealTimeClock", "Stand-Alone Real Time Clock | (SART)"),
+            Addr::CenterPanel1 => ("CenterPanel1", "Center Panel MUX Switch Pack #1"),
+            Addr::CenterPanel2 => ("CenterPanel2", "Center Panel MUX Switch Pack #2"),
+            Addr::CenterPanel3 => ("CenterPanel3", "Center Panel MUX Switch Pack #3"),
+            Addr::CenterPanel4 => ("CenterPanel4", "Center Panel MUX Switch Pack #4"),
+            Addr::CenterPanel5 => ("CenterPanel5", "Center Panel MUX Switch Pack #5"),
+            Addr::WabcoOnGuardRadar => ("WabcoOnGuardRadar", "Wabco OnGuard Radar | OnGuard Display | Collison Mitigation System"),
+            Addr::SecondaryInstrumentCluster => ("SecondaryInstrumentCluster", "Secondary Instrument Cluster | (SIC)"),
+            Addr::OffboardDiagnostics => ("OffboardDiagnostics", "Offboard Diagnostics"),
+            Addr::Trailer3Bridge => ("Trailer3Bridge", "Trailer #3 Bridge"),
+            Addr::Trailer2Bridge => ("Trailer2Bridge", "Trailer #2 Bridge"),
+            Addr::Trailer1Bridge => ("Trailer1Bridge", "Trailer #1 Bridge"),
+            Addr::SafetyDirectProcessor => ("SafetyDirectProcessor", "Bendix Camera | Safety Direct Processor (SDP) Module"),
+            Addr::ForwardRoadImageProcessor => ("ForwardRoadImageProcessor", "Forward Road Image Processor | PAM Module | Lane Departure Warning (LDW) Module | (VRDU)"),
+            Addr::LeftRearDoorPod => ("LeftRearDoorPod", "Left Rear Door Pod"),
+            Addr::RightRearDoorPod => ("RightRearDoorPod", "Right Rear Door Pod"),
+            Addr::DoorController1 => ("DoorController1", "Door Controller #1"),
+            Addr::DoorController2 => ("DoorController2", "Door Controller #2"),
+            Addr::Tachograph => ("Tachograph", "Tachograph | (TCO)"),
+            Addr::HybridSystem => ("HybridSystem", "Hybrid System"),
+            Addr::AuxiliaryPowerUnit => ("AuxiliaryPowerUnit", "Auxiliary Power Unit | (APU)"),
+            Addr::ServiceTool => ("ServiceTool", "Service Tool"),
+            Addr::TractorEcu => ("TractorEcu", "Tractor ECU | (TECU)"),
+            Addr::TaskController => ("TaskController", "Task Controller | (TC)"),
+            Addr::SupplementalEngineControlSensing => ("SupplementalEngineControlSensing", "Supplemental Engine Control Sensing"),
+            Addr::LaserReceiverRight => ("LaserReceiverRight", "Laser Receiver | Mast Right or Blade Right"),
+            Addr::LandLevelingOperatorInterface => ("LandLevelingOperatorInterface", "Land Leveling System Operator Interface"),
+            Addr::LandLevelingElectricMast => ("LandLevelingElectricMast", "Land Leveling Electric Mast"),
+            Addr::LandLevelingSupervisor => ("LandLevelingSupervisor", "Single Land Leveling System Supervisor"),
+            Addr::LandLevelingDisplay => ("LandLevelingDisplay", "Land Leveling System Display"),
+            Addr::LaserTracer => ("LaserTracer", "Laser Tracer"),
+            Addr::LoaderControl => ("LoaderControl", "Loader Control"),
+            Addr::LaserReceiverLeft => ("LaserReceiverLeft", "Laser Receiver | Mast Left or Blade Left"),
+            Addr::PropulsionSensorsGateway1 => ("PropulsionSensorsGateway1", "Propulsion Sensors & Gateway #1"),
+            Addr::PropulsionSensorsGateway2 => ("PropulsionSensorsGateway2", "Propulsion Sensors & Gateway #2"),
+            Addr::PropulsionSensorsGateway3 => ("PropulsionSensorsGateway3", "Propulsion Sensors & Gateway #3"),
+            Addr::PropulsionSensorsGateway4 => ("PropulsionSensorsGateway4", "Propulsion Sensors & Gateway #4"),
+            Addr::SourceAddressRequest0 => ("SourceAddressRequest0", "Source Address Request 0"),
+            Addr::SourceAddressRequest1 => ("SourceAddressRequest1", "Source Address Request 1"),
+            Addr::Unknown(_) => ("Unknown", "Unknown"),
+        }
+    }
+
+    /// Returns an iterator over every known address, excluding [`Addr::Unknown`].
+    ///
+    /// Addresses specific to an industry group are included, so several variants may share the
+    /// same value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let engines = Addr::iter_known().filter(|addr| u8::from(*addr) <= 1);
+    ///
+    /// assert_eq!(2, engines.count());
+    /// assert!(Addr::iter_known().all(|addr| !matches!(addr, Addr::Unknown(_))));
+    /// ```
+    pub fn iter_known() -> impl Iterator<Item = Addr> {
+        KNOWN_ADDRS.iter().copied()
+    }
+
+    /// Returns the description of the address, as displayed.
+    ///
+    /// # Returns
+    /// - `Some(&str)`: If the address is known.
+    /// - `None`: If the address is [`Addr::Unknown`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// assert_eq!(Some("Service Tool"), Addr::ServiceTool.description());
+    /// assert_eq!(None, Addr::Unknown(42).description());
+    /// ```
+    #[must_use]
+    pub const fn description(self) -> Option<&'static str> {
+        match self {
+            Addr::Unknown(_) => None,
+            addr => Some(addr.names().1),
+        }
+    }
+
+    /// Translates an address within an industry group.
+    ///
+    /// Addresses 128 to 247 are assigned per industry group; every other address means the same
+    /// thing in all industry groups. The global and on-highway industry groups use the same table
+    /// as [`From<u8>`](#impl-From<u8>-for-Addr).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let agricultural = IndustryGroup::Agricultural;
+    /// let marine = IndustryGroup::Marine;
+    ///
+    /// assert_eq!(Addr::Iteris, Addr::from_industry_group(139, IndustryGroup::OnHighway));
+    /// assert_eq!(Addr::TaskController, Addr::from_industry_group(247, agricultural));
+    /// assert_eq!(Addr::Unknown(139), Addr::from_industry_group(139, marine));
+    ///
+    /// // Outside 128 to 247, the industry group does not matter.
+    /// assert_eq!(Addr::Brakes, Addr::from_industry_group(11, marine));
+    /// ```
+    #[must_use]
+    pub fn from_industry_group(address: u8, industry_group: IndustryGroup) -> Self {
+        if !(128..=247).contains(&address) {
+            return address.into();
+        }
+
+        match (industry_group, address) {
+            (IndustryGroup::Global | IndustryGroup::OnHighway, a) => a.into(),
+            (IndustryGroup::Agricultural, 240) => Addr::TractorEcu,
+            (IndustryGroup::Agricultural, 247) => Addr::TaskController,
+            (IndustryGroup::Construction, 128) => Addr::SupplementalEngineControlSensing,
+            (IndustryGroup::Construction, 129) => Addr::LaserReceiverRight,
+            (IndustryGroup::Construction, 130) => Addr::LandLevelingOperatorInterface,
+            (IndustryGroup::Construction, 131) => Addr::LandLevelingElectricMast,
+            (IndustryGroup::Construction, 132) => Addr::LandLevelingSupervisor,
+            (IndustryGroup::Construction, 133) => Addr::LandLevelingDisplay,
+            (IndustryGroup::Construction, 134) => Addr::LaserTracer,
+            (IndustryGroup::Construction, 135) => Addr::LoaderControl,
+            (IndustryGroup::Construction, 136) => Addr::LaserReceiverLeft,
+            (IndustryGroup::Marine, 128) => Addr::PropulsionSensorsGateway1,
+            (IndustryGroup::Marine, 129) => Addr::PropulsionSensorsGateway2,
+            (IndustryGroup::Marine, 130) => Addr::PropulsionSensorsGateway3,
+            (IndustryGroup::Marine, 131) => Addr::PropulsionSensorsGateway4,
+            (_, a) => Addr::Unknown(a),
+        }
+    }
+}
+
+impl FromStr for Addr {
+    type Err = Error;
+
+    /// Parses an address from its variant name, such as `"Brakes"`, or its description, such as
+    /// `"Retarder, Exhaust, Engine #1"`. `"Unknown(n)"` parses into [`Addr::Unknown`].
+    ///
+    /// The following SAE J1939 names are accepted as aliases:
+    ///
+    /// | Alias                  | Address                                 |
+    /// |------------------------|-----------------------------------------|
+    /// | `"Engine1"`            | [`Addr::PrimaryEngineController`]       |
+    /// | `"Engine2"`            | [`Addr::SecondaryEngineController`]     |
+    /// | `"Transmission1"`      | [`Addr::PrimaryTransmissionController`] |
+    /// | `"InstrumentCluster1"` | [`Addr::InstrumentCluster`]             |
+    ///
+    /// Matching is case-sensitive and no other spelling is accepted.
+    ///
+    /// # Errors
+    /// - [`Error::UnknownAddrName`] if the string names no known address.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let known = KNOWN_ADDRS.iter().copied().find(|addr| {
+            let (name, description) = addr.names();
+            s == name || s == description
+        });
+        let alias = || {
+            ADDR_ALIASES
+                .iter()
+                .find(|&&(alias, _)| s == alias)
+                .map(|&(_, addr)| addr)
+        };
+
+        if let Some(addr) = known.or_else(alias) {
+            return Ok(addr);
+        }
+
+        s.strip_prefix("Unknown(")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .and_then(|num| num.parse().ok())
+            .map(Addr::Unknown)
+            .ok_or(Error::UnknownAddrName)
+    }
+}
+
 impl From<Addr> for u8 {
     fn from(value: Addr) -> Self {
         match value {
@@ -319,6 +670,21 @@ impl From<Addr> for u8 {
             Addr::HybridSystem => 239,
             Addr::AuxiliaryPowerUnit => 247,
             Addr::ServiceTool => 249,
+            Addr::TractorEcu => 240,
+            Addr::TaskController => 247,
+            Addr::SupplementalEngineControlSensing => 128,
+            Addr::LaserReceiverRight => 129,
+            Addr::LandLevelingOperatorInterface => 130,
+            Addr::LandLevelingElectricMast => 131,
+            Addr::LandLevelingSupervisor => 132,
+            Addr::LandLevelingDisplay => 133,
+            Addr::LaserTracer => 134,
+            Addr::LoaderControl => 135,
+            Addr::LaserReceiverLeft => 136,
+            Addr::PropulsionSensorsGateway1 => 128,
+            Addr::PropulsionSensorsGateway2 => 129,
+            Addr::PropulsionSensorsGateway3 => 130,
+            Addr::PropulsionSensorsGateway4 => 131,
             Addr::SourceAddressRequest0 => 254,
             Addr::SourceAddressRequest1 => 255,
             Addr::Unknown(a) => a,
@@ -328,67 +694,10 @@ impl From<Addr> for u8 {
 
 #[cfg(feature = "alloc")]
 impl Display for Addr {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match *self {
-            Addr::PrimaryEngineController => write!(f, "Primary Engine Controller | (CPC, ECM)"),
-            Addr::SecondaryEngineController => write!(f, "Secondary Engine Controller | (MCM, ECM #2)"),
-            Addr::PrimaryTransmissionController => write!(f, "Primary Transmission Controller | (TCM)"),
-            Addr::TransmissionShiftSelector => write!(f, "Transmission Shift Selector | (TSS)"),
-            Addr::Brakes => write!(f, "Brakes | System Controller (ABS)"),
-            Addr::Retarder => write!(f, "Retarder"),
-            Addr::CruiseControl => write!(f, "Cruise Control | (IPM, PCC)"),
-            Addr::FuelSystem => write!(f, "Fuel System | Controller (CNG)"),
-            Addr::SteeringController => write!(f, "Steering Controller | (SAS)"),
-            Addr::InstrumentCluster => write!(f, "Instrument Guage Cluster (EGC) | (ICU, RX)"),
-            Addr::ClimateControl1 => write!(f, "Climate Control #1 | (FCU)"),
-            Addr::Compass => write!(f, "Compass"),
-            Addr::BodyController => write!(f, "Body Controller | (SSAM, SAM-CAB, BHM)"),
-            Addr::OffVehicleGateway => write!(f, "Off-Vehicle Gateway | (CGW)"),
-            Addr::DidVid => write!(f, "Vehicle Information Display | Driver Information Display"),
-            Addr::RetarderExhaustEngine1 => write!(f, "Retarder, Exhaust, Engine #1"),
-            Addr::HeadwayController => write!(f, "Headway Controller | (RDF) | (OnGuard)"),
-            Addr::Suspension => write!(f, "Suspension | System Controller (ECAS)"),
-            Addr::CabController => write!(f, "Cab Controller | Primary (MSF, SHM, ECC)"),
-            Addr::TirePressureController => write!(f, "Tire Pressure Controller | (TPMS)"),
-            Addr::LightingControlModule => write!(f, "Lighting Control Module | (LCM)"),
-            Addr::ClimateControl2 => write!(f, "Climate Control #2 | Rear HVAC | (ParkSmart)"),
-            Addr::ExhaustEmissionController => write!(f, "Exhaust Emission Controller | (ACM) | (DCU)"),
-            Addr::AuxiliaryHeater => write!(f, "Auxiliary Heater | (ACU)"),
-            Addr::ChassisController => write!(f, "Chassis Controller | (CHM, SAM-Chassis)"),
-            Addr::CommunicationsUnit => write!(f, "Communications Unit | Cellular (CTP, VT)"),
-            Addr::Radio => write!(f, "Radio"),
-            Addr::SafetyRestraintSystem => write!(f, "Safety Restraint System | Air Bag | (SRS)"),
-            Addr::AftertreatmentControlModule => write!(f, "Aftertreatment Control Module | (ACM)"),
-            Addr::MultiPurposeCamera => write!(f, "Multi-Purpose Camera | (MPC)"),
-            Addr::SwitchExpansionModule => write!(f, "Switch Expansion Module | (SEM #1)"),
-            Addr::AuxillaryGaugeSwitchPack => write!(f, "Auxiliary Gauge Switch Pack | (AGSP3)"),
-            Addr::Iteris => write!(f, "Iteris"),
-            Addr::QualcommPeopleNetTranslatorBox => write!(f, "Qualcomm - PeopleNet Translator Box"),
-            Addr::StandAloneRealTimeClock => write!(f, "Stand-Alone Real Time Clock | (SART)"),
-            Addr::CenterPanel1 => write!(f, "Center Panel MUX Switch Pack #1"),
-            Addr::CenterPanel2 => write!(f, "Center Panel MUX Switch Pack #2"),
-            Addr::CenterPanel3 => write!(f, "Center Panel MUX Switch Pack #3"),
-            Addr::CenterPanel4 => write!(f, "Center Panel MUX Switch Pack #4"),
-            Addr::CenterPanel5 => write!(f, "Center Panel MUX Switch Pack #5"),
-            Addr::WabcoOnGuardRadar => write!(f, "Wabco OnGuard Radar | OnGuard Display | Collison Mitigation System"),
-            Addr::SecondaryInstrumentCluster => write!(f, "Secondary Instrument Cluster | (SIC)"),
-            Addr::OffboardDiagnostics => write!(f, "Offboard Diagnostics"),
-            Addr::Trailer3Bridge => write!(f, "Trailer #3 Bridge"),
-            Addr::Trailer2Bridge => write!(f, "Trailer #2 Bridge"),
-            Addr::Trailer1Bridge => write!(f, "Trailer #1 Bridge"),
-            Addr::SafetyDirectProcessor => write!(f, "Bendix Camera | Safety Direct Processor (SDP) Module"),
-            Addr::ForwardRoadImageProcessor => write!(f, "Forward Road Image Processor | PAM Module | Lane Departure Warning (LDW) Module | (VRDU)"),
-            Addr::LeftRearDoorPod => write!(f, "Left Rear Door Pod"),
-            Addr::RightRearDoorPod => write!(f, "Right Rear Door Pod"),
-            Addr::DoorController1 => write!(f, "Door Controller #1"),
-            Addr::DoorController2 => write!(f, "Door Controller #2"),
-            Addr::Tachograph => write!(f, "Tachograph | (TCO)"),
-            Addr::HybridSystem => write!(f, "Hybrid System"),
-            Addr::AuxiliaryPowerUnit => write!(f, "Auxiliary Power Unit | (APU)"),
-            Addr::ServiceTool => write!(f, "Service Tool"),
-            Addr::SourceAddressRequest0 => write!(f, "Source Address Request 0"),
-            Addr::SourceAddressRequest1 => write!(f, "Source Address Request 1"),
             Addr::Unknown(num) => write!(f, "Unknown({num})"),
+            addr => f.write_str(addr.names().1),
         }
     }
 }
@@ -424,6 +733,21 @@ impl SourceAddr {
             SourceAddr::None => None,
         }
     }
+
+    /// Lookup and translate the [`SourceAddr`] object within an industry group.
+    ///
+    /// See [`Addr::from_industry_group`].
+    ///
+    /// # Returns
+    /// - `Some(Addr)`: If a source address is present.
+    /// - `None`: If there is no source address.
+    #[must_use]
+    pub fn lookup_in(self, industry_group: IndustryGroup) -> Option<Addr> {
+        match self {
+            SourceAddr::Some(value) => Some(Addr::from_industry_group(value, industry_group)),
+            SourceAddr::None => None,
+        }
+    }
 }
 
 impl DestinationAddr {
@@ -439,6 +763,21 @@ impl DestinationAddr {
             DestinationAddr::None => None,
         }
     }
+
+    /// Lookup and translate the [`DestinationAddr`] object within an industry group.
+    ///
+    /// See [`Addr::from_industry_group`].
+    ///
+    /// # Returns
+    /// - `Some(Addr)`: If a destination address is present.
+    /// - `None`: If the pdu specific bits do not contain a destination address.
+    #[must_use]
+    pub fn lookup_in(self, industry_group: IndustryGroup) -> Option<Addr> {
+        match self {
+            DestinationAddr::Some(value) => Some(Addr::from_industry_group(value, industry_group)),
+            DestinationAddr::None => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -458,4 +797,95 @@ mod sa_tests {
         let sa_value: u8 = Addr::RetarderExhaustEngine1.into();
         assert_eq!(41, sa_value)
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_from_str() -> Result<(), Error> {
+        assert_eq!(Addr::Brakes, "Brakes".parse()?);
+        assert_eq!(
+            Addr::RetarderExhaustEngine1,
+            "RetarderExhaustEngine1".parse()?
+        );
+        assert_eq!(
+            Addr::RetarderExhaustEngine1,
+            "Retarder, Exhaust, Engine #1".parse()?
+        );
+        assert_eq!(Addr::Unknown(42), "Unknown(42)".parse()?);
+        assert_eq!(
+            Addr::PrimaryTransmissionController,
+            "Transmission1".parse()?
+        );
+        assert_eq!(Addr::PrimaryEngineController, "Engine1".parse()?);
+        assert_eq!(Err(Error::UnknownAddrName), "transmission1".parse::<Addr>());
+        assert_eq!(Err(Error::UnknownAddrName), "Transmission9".parse::<Addr>());
+        assert_eq!(Err(Error::UnknownAddrName), "Unknown(256)".parse::<Addr>());
+
+        // Every known address round-trips through its name and its description.
+        for addr in Addr::iter_known() {
+            assert_eq!(Ok(addr), format!("{addr:?}").parse());
+            assert_eq!(Ok(addr), format!("{addr}").parse());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_known() {
+        assert_eq!(73, Addr::iter_known().count());
+        assert!(Addr::iter_known().any(|addr| addr == Addr::ServiceTool));
+
+        // Every address translated from a number is listed.
+        for group in [
+            IndustryGroup::OnHighway,
+            IndustryGroup::Agricultural,
+            IndustryGroup::Construction,
+            IndustryGroup::Marine,
+        ] {
+            for num in 0..=u8::MAX {
+                let addr = Addr::from_industry_group(num, group);
+                assert!(addr == Addr::Unknown(num) || Addr::iter_known().any(|a| a == addr));
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookup_in() {
+        let sa_a = SourceAddr::Some(128);
+        let da_a = DestinationAddr::Some(240);
+
+        assert_eq!(sa_a.lookup(), sa_a.lookup_in(IndustryGroup::Global));
+        assert_eq!(
+            Some(Addr::SwitchExpansionModule),
+            sa_a.lookup_in(IndustryGroup::OnHighway)
+        );
+        assert_eq!(
+            Some(Addr::SupplementalEngineControlSensing),
+            sa_a.lookup_in(IndustryGroup::Construction)
+        );
+        assert_eq!(
+            Some(Addr::PropulsionSensorsGateway1),
+            sa_a.lookup_in(IndustryGroup::Marine)
+        );
+        assert_eq!(
+            Some(Addr::Unknown(128)),
+            sa_a.lookup_in(IndustryGroup::Industrial)
+        );
+        assert_eq!(
+            Some(Addr::TractorEcu),
+            da_a.lookup_in(IndustryGroup::Agricultural)
+        );
+        assert_eq!(
+            Some(Addr::ServiceTool),
+            SourceAddr::Some(249).lookup_in(IndustryGroup::Agricultural)
+        );
+        assert_eq!(None, DestinationAddr::None.lookup_in(IndustryGroup::Marine));
+
+        // Every translated address encodes back to itself.
+        for group in 0..8 {
+            for address in 0..=255 {
+                let addr = Addr::from_industry_group(address, IndustryGroup::from(group));
+                assert_eq!(address, u8::from(addr));
+            }
+        }
+    }
 }
@@ -0,0 +1,1403 @@
+// Copyright (c) 2024 Nathan H. Keough
+//
+// This work is dual-licensed under MIT OR Apache 2.0 (or any later version).
+// You may choose between one of them if you use this work.
+//
+// For further detail, please refer to the individual licenses located at the root of this crate.
+
+//! # J1939 Diagnostic Messages
+//!
+//! **Description:**
+//! SAE J1939-73 defines the diagnostic messages (DMs) used to report and manage faults. The most
+//! common is DM1 (PGN 65226), which every controller with an active fault broadcasts once per
+//! second.
+//!
+//! A DM1 payload starts with two bytes of lamp status, followed by one four-byte Diagnostic
+//! Trouble Code (DTC) per active fault:
+//!
+//! | Byte | Content                                                              |
+//! |------|----------------------------------------------------------------------|
+//! | 1    | Lamp status: MIL (bits 8-7), RSL (bits 6-5), AWL (bits 4-3), PL (bits 2-1) |
+//! | 2    | Lamp flash: same layout as byte 1                                   |
+//! | 3    | SPN, bits 8-1 of the SPN                                             |
+//! | 4    | SPN, bits 16-9 of the SPN                                            |
+//! | 5    | SPN bits 19-17 (bits 8-6), FMI (bits 5-1)                            |
+//! | 6    | SPN conversion method (bit 8), occurrence count (bits 7-1)           |
+//!
+//! A payload with a single active fault fits in one frame, padded with `0xFF`. Payloads with
+//! several faults are sent with the transport protocol and decoded from the reassembled bytes.
+//!
+//! DM2 and DM12 share this layout. DM3 and DM11 carry no data: requesting them with a
+//! [`DiagnosticRequest`] clears the previously active or active DTCs of the destination. DM13
+//! asks controllers to stop or start broadcasting, for example while a service tool reprograms
+//! a controller.
+//!
+//! **Source Documents:**
+//! - *SAE J1939-73*
+
+use core::{
+    fmt::{self, Display, Formatter},
+    slice::ChunksExact,
+};
+
+use crate::{
+    conversion::Conversion,
+    error::Error,
+    message::Message,
+    payload::{Data, Pdu},
+};
+
+use super::{
+    address::{DestinationAddr, SourceAddr},
+    identifier::J1939,
+    pgn::{build_message, check_pgn, KnownPgn, Pgn},
+    spn::Spn,
+};
+
+/// Represents the on/off status of a diagnostic lamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LampStatus {
+    /// The lamp is off.
+    Off,
+    /// The lamp is on.
+    On,
+    /// Reserved value.
+    Reserved,
+    /// The lamp status is not available.
+    NotAvailable,
+}
+
+impl From<u8> for LampStatus {
+    fn from(value: u8) -> Self {
+        match value & 0b11 {
+            0b00 => LampStatus::Off,
+            0b01 => LampStatus::On,
+            0b10 => LampStatus::Reserved,
+            _ => LampStatus::NotAvailable,
+        }
+    }
+}
+
+/// Represents the flash state of a diagnostic lamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlashStatus {
+    /// The lamp flashes slowly, at 1 Hz.
+    SlowFlash,
+    /// The lamp flashes quickly, at 2 Hz or faster.
+    FastFlash,
+    /// Reserved value.
+    Reserved,
+    /// The lamp does not flash, or the flash state is not available.
+    DoNotFlash,
+}
+
+impl From<LampStatus> for u8 {
+    fn from(value: LampStatus) -> Self {
+        match value {
+            LampStatus::Off => 0b00,
+            LampStatus::On => 0b01,
+            LampStatus::Reserved => 0b10,
+            LampStatus::NotAvailable => 0b11,
+        }
+    }
+}
+
+impl From<u8> for FlashStatus {
+    fn from(value: u8) -> Self {
+        match value & 0b11 {
+            0b00 => FlashStatus::SlowFlash,
+            0b01 => FlashStatus::FastFlash,
+            0b10 => FlashStatus::Reserved,
+            _ => FlashStatus::DoNotFlash,
+        }
+    }
+}
+
+impl From<FlashStatus> for u8 {
+    fn from(value: FlashStatus) -> Self {
+        match value {
+            FlashStatus::SlowFlash => 0b00,
+            FlashStatus::FastFlash => 0b01,
+            FlashStatus::Reserved => 0b10,
+            FlashStatus::DoNotFlash => 0b11,
+        }
+    }
+}
+
+/// Represents the status and flash state of a single diagnostic lamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lamp {
+    status: LampStatus,
+    flash: FlashStatus,
+}
+
+impl Lamp {
+    /// Creates a new lamp from its status and flash state.
+    #[inline]
+    #[must_use]
+    pub const fn new(status: LampStatus, flash: FlashStatus) -> Self {
+        Self { status, flash }
+    }
+
+    /// Returns the on/off status of the lamp.
+    #[inline]
+    #[must_use]
+    pub const fn status(&self) -> LampStatus {
+        self.status
+    }
+
+    /// Returns the flash state of the lamp.
+    #[inline]
+    #[must_use]
+    pub const fn flash(&self) -> FlashStatus {
+        self.flash
+    }
+
+    /// Returns `true` if the lamp is on.
+    #[inline]
+    #[must_use]
+    pub const fn is_on(&self) -> bool {
+        matches!(self.status, LampStatus::On)
+    }
+}
+
+/// Represents the four diagnostic lamps reported by DM1 and related diagnostic messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lamps {
+    bytes: [u8; 2],
+}
+
+impl Lamps {
+    /// Creates the lamps from the Malfunction Indicator Lamp, Red Stop Lamp, Amber Warning Lamp
+    /// and Protect Lamp.
+    #[must_use]
+    pub fn new(mil: Lamp, rsl: Lamp, awl: Lamp, pl: Lamp) -> Self {
+        let mut bytes = [0; 2];
+
+        for (lamp, shift) in [(mil, 6), (rsl, 4), (awl, 2), (pl, 0)] {
+            bytes[0] |= u8::from(lamp.status) << shift;
+            bytes[1] |= u8::from(lamp.flash) << shift;
+        }
+
+        Self { bytes }
+    }
+
+    /// Creates the lamps from the lamp status byte and the lamp flash byte.
+    #[inline]
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; 2]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the lamp status byte and the lamp flash byte.
+    #[inline]
+    #[must_use]
+    pub const fn to_bytes(self) -> [u8; 2] {
+        self.bytes
+    }
+
+    /// Decodes the lamp whose two-bit fields start at `shift`.
+    fn lamp(self, shift: u8) -> Lamp {
+        Lamp {
+            status: LampStatus::from(self.bytes[0] >> shift),
+            flash: FlashStatus::from(self.bytes[1] >> shift),
+        }
+    }
+
+    /// Returns the Malfunction Indicator Lamp (MIL), used for emissions-related faults.
+    #[inline]
+    #[must_use]
+    pub fn mil(self) -> Lamp {
+        self.lamp(6)
+    }
+
+    /// Returns the Red Stop Lamp (RSL), used for faults severe enough to stop the vehicle.
+    #[inline]
+    #[must_use]
+    pub fn rsl(self) -> Lamp {
+        self.lamp(4)
+    }
+
+    /// Returns the Amber Warning Lamp (AWL), used for faults that do not require stopping.
+    #[inline]
+    #[must_use]
+    pub fn awl(self) -> Lamp {
+        self.lamp(2)
+    }
+
+    /// Returns the Protect Lamp (PL), used for faults outside the electronic subsystem.
+    #[inline]
+    #[must_use]
+    pub fn pl(self) -> Lamp {
+        self.lamp(0)
+    }
+}
+
+/// The [`Fmi`] enum represents the Failure Mode Identifiers (FMIs) defined by SAE J1939-73. The
+/// FMI of a [`Dtc`] describes how the parameter identified by its SPN has failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fmi {
+    /// Data valid but above normal operational range - most severe level
+    DataAboveNormalMostSevere,
+
+    /// Data valid but below normal operational range - most severe level
+    DataBelowNormalMostSevere,
+
+    /// Data erratic, intermittent or incorrect
+    DataErratic,
+
+    /// Voltage above normal, or shorted to high source
+    VoltageAboveNormal,
+
+    /// Voltage below normal, or shorted to low source
+    VoltageBelowNormal,
+
+    /// Current below normal or open circuit
+    CurrentBelowNormal,
+
+    /// Current above normal or grounded circuit
+    CurrentAboveNormal,
+
+    /// Mechanical system not responding or out of adjustment
+    MechanicalSystemNotResponding,
+
+    /// Abnormal frequency or pulse width or period
+    AbnormalFrequency,
+
+    /// Abnormal update rate
+    AbnormalUpdateRate,
+
+    /// Abnormal rate of change
+    AbnormalRateOfChange,
+
+    /// Root cause not known
+    RootCauseNotKnown,
+
+    /// Bad intelligent device or component
+    BadIntelligentDevice,
+
+    /// Out of calibration
+    OutOfCalibration,
+
+    /// Special instructions
+    SpecialInstructions,
+
+    /// Data valid but above normal operating range - least severe level
+    DataAboveNormalLeastSevere,
+
+    /// Data valid but above normal operating range - moderately severe level
+    DataAboveNormalModeratelySevere,
+
+    /// Data valid but below normal operating range - least severe level
+    DataBelowNormalLeastSevere,
+
+    /// Data valid but below normal operating range - moderately severe level
+    DataBelowNormalModeratelySevere,
+
+    /// Received network data in error
+    ReceivedNetworkDataInError,
+
+    /// Data drifted high
+    DataDriftedHigh,
+
+    /// Data drifted low
+    DataDriftedLow,
+
+    /// Reserved for SAE assignment (FMIs 22 to 30)
+    /// Contains the FMI value.
+    Reserved(u8),
+
+    /// Condition exists
+    ConditionExists,
+}
+
+impl From<u8> for Fmi {
+    /// Converts the low five bits of `value` into a [`Fmi`].
+    fn from(value: u8) -> Self {
+        match value & 0x1F {
+            0 => Fmi::DataAboveNormalMostSevere,
+            1 => Fmi::DataBelowNormalMostSevere,
+            2 => Fmi::DataErratic,
+            3 => Fmi::VoltageAboveNormal,
+            4 => Fmi::VoltageBelowNormal,
+            5 => Fmi::CurrentBelowNormal,
+            6 => Fmi::CurrentAboveNormal,
+            7 => Fmi::MechanicalSystemNotResponding,
+            8 => Fmi::AbnormalFrequency,
+            9 => Fmi::AbnormalUpdateRate,
+            10 => Fmi::AbnormalRateOfChange,
+            11 => Fmi::RootCauseNotKnown,
+            12 => Fmi::BadIntelligentDevice,
+            13 => Fmi::OutOfCalibration,
+            14 => Fmi::SpecialInstructions,
+            15 => Fmi::DataAboveNormalLeastSevere,
+            16 => Fmi::DataAboveNormalModeratelySevere,
+            17 => Fmi::DataBelowNormalLeastSevere,
+            18 => Fmi::DataBelowNormalModeratelySevere,
+            19 => Fmi::ReceivedNetworkDataInError,
+            20 => Fmi::DataDriftedHigh,
+            21 => Fmi::DataDriftedLow,
+            31 => Fmi::ConditionExists,
+            f => Fmi::Reserved(f),
+        }
+    }
+}
+
+impl From<Fmi> for u8 {
+    fn from(value: Fmi) -> Self {
+        match value {
+            Fmi::DataAboveNormalMostSevere => 0,
+            Fmi::DataBelowNormalMostSevere => 1,
+            Fmi::DataErratic => 2,
+            Fmi::VoltageAboveNormal => 3,
+            Fmi::VoltageBelowNormal => 4,
+            Fmi::CurrentBelowNormal => 5,
+            Fmi::CurrentAboveNormal => 6,
+            Fmi::MechanicalSystemNotResponding => 7,
+            Fmi::AbnormalFrequency => 8,
+            Fmi::AbnormalUpdateRate => 9,
+            Fmi::AbnormalRateOfChange => 10,
+            Fmi::RootCauseNotKnown => 11,
+            Fmi::BadIntelligentDevice => 12,
+            Fmi::OutOfCalibration => 13,
+            Fmi::SpecialInstructions => 14,
+            Fmi::DataAboveNormalLeastSevere => 15,
+            Fmi::DataAboveNormalModeratelySevere => 16,
+            Fmi::DataBelowNormalLeastSevere => 17,
+            Fmi::DataBelowNormalModeratelySevere => 18,
+            Fmi::ReceivedNetworkDataInError => 19,
+            Fmi::DataDriftedHigh => 20,
+            Fmi::DataDriftedLow => 21,
+            Fmi::Reserved(f) => f,
+            Fmi::ConditionExists => 31,
+        }
+    }
+}
+
+impl Display for Fmi {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Fmi::DataAboveNormalMostSevere => write!(
+                f,
+                "Data valid but above normal operational range - most severe level"
+            ),
+            Fmi::DataBelowNormalMostSevere => write!(
+                f,
+                "Data valid but below normal operational range - most severe level"
+            ),
+            Fmi::DataErratic => write!(f, "Data erratic, intermittent or incorrect"),
+            Fmi::VoltageAboveNormal => write!(f, "Voltage above normal, or shorted to high source"),
+            Fmi::VoltageBelowNormal => write!(f, "Voltage below normal, or shorted to low source"),
+            Fmi::CurrentBelowNormal => write!(f, "Current below normal or open circuit"),
+            Fmi::CurrentAboveNormal => write!(f, "Current above normal or grounded circuit"),
+            Fmi::MechanicalSystemNotResponding => {
+                write!(f, "Mechanical system not responding or out of adjustment")
+            }
+            Fmi::AbnormalFrequency => write!(f, "Abnormal frequency or pulse width or period"),
+            Fmi::AbnormalUpdateRate => write!(f, "Abnormal update rate"),
+            Fmi::AbnormalRateOfChange => write!(f, "Abnormal rate of change"),
+            Fmi::RootCauseNotKnown => write!(f, "Root cause not known"),
+            Fmi::BadIntelligentDevice => write!(f, "Bad intelligent device or component"),
+            Fmi::OutOfCalibration => write!(f, "Out of calibration"),
+            Fmi::SpecialInstructions => write!(f, "Special instructions"),
+            Fmi::DataAboveNormalLeastSevere => write!(
+                f,
+                "Data valid but above normal operating range - least severe level"
+            ),
+            Fmi::DataAboveNormalModeratelySevere => write!(
+                f,
+                "Data valid but above normal operating range - moderately severe level"
+            ),
+            Fmi::DataBelowNormalLeastSevere => write!(
+                f,
+                "Data valid but below normal operating range - least severe level"
+            ),
+            Fmi::DataBelowNormalModeratelySevere => write!(
+                f,
+                "Data valid but below normal operating range - moderately severe level"
+            ),
+            Fmi::ReceivedNetworkDataInError => write!(f, "Received network data in error"),
+            Fmi::DataDriftedHigh => write!(f, "Data drifted high"),
+            Fmi::DataDriftedLow => write!(f, "Data drifted low"),
+            Fmi::Reserved(_) => write!(f, "Reserved for SAE assignment"),
+            Fmi::ConditionExists => write!(f, "Condition exists"),
+        }
+    }
+}
+
+/// Returns a short, human-readable label for the most common diagnostic SPNs.
+///
+/// SPNs not listed here fall back to the name of the matching entry of [`SPNS`](super::spn::SPNS).
+///
+/// # Returns
+/// - `Some(label)`: If the SPN is known.
+/// - `None`: If the SPN is unknown.
+#[must_use]
+pub fn spn_label(spn: u32) -> Option<&'static str> {
+    let label = match spn {
+        27 => "EGR Valve Position",
+        97 => "Water In Fuel Indicator",
+        157 => "Fuel Rail Pressure",
+        158 => "Keyswitch Battery Potential",
+        412 => "EGR Temperature",
+        629 => "Controller #1",
+        639 => "J1939 Network #1",
+        651 => "Injector Cylinder #1",
+        652 => "Injector Cylinder #2",
+        653 => "Injector Cylinder #3",
+        654 => "Injector Cylinder #4",
+        655 => "Injector Cylinder #5",
+        656 => "Injector Cylinder #6",
+        723 => "Camshaft Speed Sensor",
+        1569 => "Engine Protection Torque Derate",
+        1761 => "DEF Tank Level",
+        3031 => "DEF Tank Temperature",
+        3216 => "Engine Exhaust NOx",
+        3226 => "Aftertreatment Outlet NOx",
+        3251 => "DPF Differential Pressure",
+        3364 => "DEF Quality",
+        3719 => "DPF Soot Load",
+        4364 => "SCR Conversion Efficiency",
+        5246 => "SCR Operator Inducement Severity",
+        _ => return Spn::lookup(spn).map(|spn| spn.name()),
+    };
+
+    Some(label)
+}
+
+/// Represents a J1939 Diagnostic Trouble Code (DTC).
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// let dtc_a = Dtc::from_bytes([0x6E, 0x00, 0x00, 0x03]);
+///
+/// assert_eq!(Fmi::DataAboveNormalMostSevere, dtc_a.fmi());
+/// assert_eq!(Some("Engine Coolant Temperature"), dtc_a.spn_label());
+/// assert_eq!("SPN 110 FMI 0 OC 3", format!("{dtc_a}"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dtc {
+    spn: u32,
+    fmi: Fmi,
+    occurrence_count: u8,
+    conversion_method: bool,
+}
+
+impl Dtc {
+    /// Creates a new DTC using the current (version 4) SPN conversion method.
+    ///
+    /// # Errors
+    /// - [`Error::FieldOutOfRange`] if `spn` exceeds 19 bits, `occurrence_count` exceeds 127, or
+    ///   `fmi` is a [`Fmi::Reserved`] value outside 22 to 30.
+    pub fn new(spn: u32, fmi: Fmi, occurrence_count: u8) -> Result<Self, Error> {
+        Error::check_field("spn", spn, 0x7_FFFF)?;
+        Error::check_field("occurrence_count", occurrence_count.into(), 0x7F)?;
+        if let Fmi::Reserved(value @ (0..=21 | 31..)) = fmi {
+            return Err(Error::FieldOutOfRange {
+                field: "fmi",
+                value: value.into(),
+                max: 30,
+            });
+        }
+
+        Ok(Self {
+            spn,
+            fmi,
+            occurrence_count,
+            conversion_method: false,
+        })
+    }
+
+    /// Decodes a DTC from its four-byte wire representation.
+    #[must_use]
+    pub fn from_bytes(bytes: [u8; 4]) -> Self {
+        Self {
+            spn: bytes[0] as u32 | (bytes[1] as u32) << 8 | ((bytes[2] >> 5) as u32) << 16,
+            fmi: Fmi::from(bytes[2]),
+            occurrence_count: bytes[3] & 0x7F,
+            conversion_method: bytes[3] & 0x80 != 0,
+        }
+    }
+
+    /// Encodes the DTC into its four-byte wire representation.
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub fn to_bytes(self) -> [u8; 4] {
+        [
+            self.spn as u8,
+            (self.spn >> 8) as u8,
+            ((self.spn >> 16) as u8) << 5 | (u8::from(self.fmi) & 0x1F),
+            u8::from(self.conversion_method) << 7 | self.occurrence_count,
+        ]
+    }
+
+    /// Returns the 19-bit Suspect Parameter Number of the faulty parameter.
+    #[inline]
+    #[must_use]
+    pub const fn spn(&self) -> u32 {
+        self.spn
+    }
+
+    /// Returns the Failure Mode Identifier.
+    #[inline]
+    #[must_use]
+    pub const fn fmi(&self) -> Fmi {
+        self.fmi
+    }
+
+    /// Returns a short, human-readable label for the SPN, see [`spn_label`].
+    #[must_use]
+    pub fn spn_label(&self) -> Option<&'static str> {
+        spn_label(self.spn)
+    }
+
+    /// Returns the number of times the fault has gone from inactive to active, up to 126.
+    /// A value of 127 means the count is not available.
+    #[inline]
+    #[must_use]
+    pub const fn occurrence_count(&self) -> u8 {
+        self.occurrence_count
+    }
+
+    /// Returns the SPN conversion method bit. It is `false` for the current (version 4) SPN
+    /// layout, which this type decodes.
+    #[inline]
+    #[must_use]
+    pub const fn conversion_method(&self) -> bool {
+        self.conversion_method
+    }
+}
+
+impl Display for Dtc {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SPN {} FMI {} OC {}",
+            self.spn,
+            u8::from(self.fmi),
+            self.occurrence_count
+        )
+    }
+}
+
+/// Iterator over the [`Dtc`]s of a diagnostic message.
+///
+/// The all-zero DTC, sent by a controller with no active faults, is skipped.
+#[derive(Debug, Clone)]
+pub struct Dtcs<'a> {
+    chunks: ChunksExact<'a, u8>,
+}
+
+impl Iterator for Dtcs<'_> {
+    type Item = Dtc;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks
+            .by_ref()
+            .map(|chunk| Dtc::from_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .find(|dtc| *dtc != Dtc::from_bytes([0; 4]))
+    }
+}
+
+/// Defines a diagnostic message made of lamps followed by a list of DTCs.
+macro_rules! dtc_message {
+    ($(#[$meta:meta])* $name:ident, $known:ident, $label:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name<B> {
+            bytes: B,
+        }
+
+        impl<B: AsRef<[u8]>> $name<B> {
+            #[doc = concat!("Creates a ", $label, " decoder over a payload.")]
+            ///
+            /// # Errors
+            /// - [`Error::InvalidDataLength`] if the payload is shorter than the 6 bytes holding
+            ///   the lamps and one DTC.
+            pub fn try_new(bytes: B) -> Result<Self, Error> {
+                match bytes.as_ref().len() {
+                    len if len < 6 => Err(Error::InvalidDataLength(len)),
+                    _ => Ok(Self { bytes }),
+                }
+            }
+
+            /// Returns the diagnostic lamps.
+            #[must_use]
+            pub fn lamps(&self) -> Lamps {
+                let bytes = self.bytes.as_ref();
+
+                Lamps::from_bytes([bytes[0], bytes[1]])
+            }
+
+            /// Returns the raw payload bytes.
+            #[inline]
+            #[must_use]
+            pub fn as_bytes(&self) -> &[u8] {
+                self.bytes.as_ref()
+            }
+
+            /// Returns an iterator over the DTCs.
+            ///
+            /// Trailing bytes that do not form a whole DTC, such as the padding of a single
+            /// frame, are ignored.
+            #[must_use]
+            pub fn dtcs(&self) -> Dtcs<'_> {
+                Dtcs {
+                    chunks: self.bytes.as_ref()[2..].chunks_exact(4),
+                }
+            }
+        }
+
+        impl $name<[u8; 8]> {
+            #[doc = concat!("Creates a single-frame ", $label, " carrying at most one DTC.")]
+            ///
+            /// Without a DTC the all-zero DTC is sent, meaning no faults are reported. Use
+            #[doc = concat!("[`", stringify!($name), "::encode_into`] to report several DTCs.")]
+            #[must_use]
+            pub fn new(lamps: Lamps, dtc: Option<Dtc>) -> Self {
+                let [status, flash] = lamps.to_bytes();
+                let [b2, b3, b4, b5] = dtc.map_or([0; 4], Dtc::to_bytes);
+
+                Self {
+                    bytes: [status, flash, b2, b3, b4, b5, 0xFF, 0xFF],
+                }
+            }
+
+            #[doc = concat!("Encodes the ", $label, " into a broadcast message.")]
+            ///
+            /// # Errors
+            /// - [`Error::MissingSourceAddr`] if `source_address` is [`SourceAddr::None`].
+            pub fn to_message(
+                self,
+                source_address: SourceAddr,
+            ) -> Result<Message<J1939, Data>, Error> {
+                build_message(
+                    KnownPgn::$known,
+                    source_address,
+                    DestinationAddr::None,
+                    self.bytes,
+                )
+            }
+        }
+
+        impl<'a> $name<&'a [u8]> {
+            #[doc = concat!("Encodes a ", $label, " carrying any number of DTCs into `buffer`.")]
+            ///
+            /// The payload takes 2 bytes of lamps plus 4 bytes per DTC, and an empty `dtcs`
+            /// encodes the all-zero DTC. Payloads longer than 8 bytes are sent with
+            /// [`transport::segment`](super::transport::segment).
+            ///
+            /// # Errors
+            /// - [`Error::InvalidDataLength`] if `buffer` is shorter than the payload.
+            pub fn encode_into(
+                lamps: Lamps,
+                dtcs: &[Dtc],
+                buffer: &'a mut [u8],
+            ) -> Result<Self, Error> {
+                let len = 2 + 4 * dtcs.len().max(1);
+                if buffer.len() < len {
+                    return Err(Error::InvalidDataLength(buffer.len()));
+                }
+
+                let bytes = &mut buffer[..len];
+
+                bytes[..2].copy_from_slice(&lamps.to_bytes());
+                bytes[2..].fill(0);
+                for (chunk, dtc) in bytes[2..].chunks_exact_mut(4).zip(dtcs) {
+                    chunk.copy_from_slice(&dtc.to_bytes());
+                }
+
+                Ok(Self { bytes })
+            }
+        }
+
+        impl From<Pdu<Data>> for $name<[u8; 8]> {
+            fn from(value: Pdu<Data>) -> Self {
+                Self {
+                    bytes: value.to_be_bytes(),
+                }
+            }
+        }
+
+        impl TryFrom<&Message<J1939, Data>> for $name<[u8; 8]> {
+            type Error = Error;
+
+            fn try_from(value: &Message<J1939, Data>) -> Result<Self, Self::Error> {
+                check_pgn(value, KnownPgn::$known)?;
+
+                Ok(value.pdu().into())
+            }
+        }
+    };
+}
+
+dtc_message! {
+    /// Decoder and encoder for DM1 (Active Diagnostic Trouble Codes, PGN 65226).
+    ///
+    /// `B` is the payload storage: `[u8; 8]` for a single frame and `&[u8]` for a payload
+    /// reassembled from the transport protocol.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// # fn main() -> Result<(), can_types::error::Error> {
+    /// // Amber warning lamp on; SPN 110 FMI 0 with 3 occurrences.
+    /// let dm1_a = Dm1::from(Pdu::<Data>::try_from_hex("04FF6E000003FFFF")?);
+    ///
+    /// assert!(dm1_a.lamps().awl().is_on());
+    ///
+    /// let dtc_a = dm1_a.dtcs().next().unwrap();
+    ///
+    /// assert_eq!(110, dtc_a.spn());
+    /// assert_eq!(3, dtc_a.occurrence_count());
+    /// # Ok(())
+    /// # }
+    /// ```
+    Dm1, Dm1, "DM1"
+}
+
+dtc_message! {
+    /// Decoder and encoder for DM2 (Previously Active Diagnostic Trouble Codes, PGN 65227).
+    ///
+    /// DM2 shares the layout of [`Dm1`] and is sent in response to a [`DiagnosticRequest::Dm2`].
+    Dm2, Dm2, "DM2"
+}
+
+dtc_message! {
+    /// Decoder and encoder for DM12 (Emissions-Related Active Diagnostic Trouble Codes,
+    /// PGN 65236).
+    ///
+    /// DM12 shares the layout of [`Dm1`] and is sent in response to a
+    /// [`DiagnosticRequest::Dm12`].
+    Dm12, Dm12, "DM12"
+}
+
+/// Represents a Request (PGN 59904) for a diagnostic message.
+///
+/// Requesting DM3 or DM11 asks the destination to clear its previously active or active DTCs;
+/// the destination answers with an acknowledgment rather than a DM3 or DM11 payload.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// # fn main() -> Result<(), can_types::error::Error> {
+/// // A service tool asks the primary engine controller to clear its active DTCs.
+/// let msg_a = DiagnosticRequest::Dm11
+///     .to_message(SourceAddr::Some(249), DestinationAddr::Some(0))?;
+///
+/// assert_eq!(IdJ1939::from_hex("18EA00F9"), msg_a.id());
+/// assert_eq!(Pdu::<Data>::from_hex("D3FE00FFFFFFFFFF"), msg_a.pdu());
+/// assert_eq!(DiagnosticRequest::Dm11, DiagnosticRequest::try_from(&msg_a)?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticRequest {
+    /// Request for DM1, the active DTCs.
+    Dm1,
+    /// Request for DM2, the previously active DTCs.
+    Dm2,
+    /// Request for DM3, clearing the previously active DTCs.
+    Dm3,
+    /// Request for DM11, clearing the active DTCs.
+    Dm11,
+    /// Request for DM12, the emissions-related active DTCs.
+    Dm12,
+}
+
+impl DiagnosticRequest {
+    /// Returns the requested parameter group.
+    #[must_use]
+    pub const fn pgn(self) -> KnownPgn {
+        match self {
+            DiagnosticRequest::Dm1 => KnownPgn::Dm1,
+            DiagnosticRequest::Dm2 => KnownPgn::Dm2,
+            DiagnosticRequest::Dm3 => KnownPgn::Dm3,
+            DiagnosticRequest::Dm11 => KnownPgn::Dm11,
+            DiagnosticRequest::Dm12 => KnownPgn::Dm12,
+        }
+    }
+
+    /// Encodes the request into a message.
+    ///
+    /// # Arguments
+    /// - `source_address`: The address of the requester.
+    /// - `destination_address`: The address of the controller to query, or
+    ///   [`DestinationAddr::None`] to query every controller (global address 255).
+    ///
+    /// # Errors
+    /// - [`Error::MissingSourceAddr`] if `source_address` is [`SourceAddr::None`].
+    pub fn to_message(
+        self,
+        source_address: SourceAddr,
+        destination_address: DestinationAddr,
+    ) -> Result<Message<J1939, Data>, Error> {
+        let [pgn_0, pgn_1, pgn_2, _] = self.pgn().pgn().into_bits().to_le_bytes();
+
+        build_message(
+            KnownPgn::Rqst,
+            source_address,
+            destination_address,
+            [pgn_0, pgn_1, pgn_2, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+        )
+    }
+}
+
+impl TryFrom<&Message<J1939, Data>> for DiagnosticRequest {
+    type Error = Error;
+
+    /// Decodes a received request.
+    ///
+    /// # Errors
+    /// - [`Error::UnexpectedPgn`] if the message is not a request, or if the requested PGN is not
+    ///   a diagnostic message.
+    fn try_from(value: &Message<J1939, Data>) -> Result<Self, Self::Error> {
+        check_pgn(value, KnownPgn::Rqst)?;
+
+        let [pgn_0, pgn_1, pgn_2, ..] = value.pdu().to_be_bytes();
+        let requested = u32::from_le_bytes([pgn_0, pgn_1, pgn_2, 0]);
+
+        match Pgn::try_from_bits(requested)?.lookup() {
+            Some(KnownPgn::Dm1) => Ok(DiagnosticRequest::Dm1),
+            Some(KnownPgn::Dm2) => Ok(DiagnosticRequest::Dm2),
+            Some(KnownPgn::Dm3) => Ok(DiagnosticRequest::Dm3),
+            Some(KnownPgn::Dm11) => Ok(DiagnosticRequest::Dm11),
+            Some(KnownPgn::Dm12) => Ok(DiagnosticRequest::Dm12),
+            _ => Err(Error::UnexpectedPgn(requested)),
+        }
+    }
+}
+
+/// Represents a DM13 broadcast command for one network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BroadcastCommand {
+    /// Stop broadcasting.
+    Stop,
+    /// Start (resume) broadcasting.
+    Start,
+    /// Reserved value.
+    Reserved,
+    /// Leave the broadcast state unchanged.
+    DontCare,
+}
+
+impl From<u8> for BroadcastCommand {
+    fn from(value: u8) -> Self {
+        match value & 0b11 {
+            0b00 => BroadcastCommand::Stop,
+            0b01 => BroadcastCommand::Start,
+            0b10 => BroadcastCommand::Reserved,
+            _ => BroadcastCommand::DontCare,
+        }
+    }
+}
+
+impl From<BroadcastCommand> for u8 {
+    fn from(value: BroadcastCommand) -> Self {
+        match value {
+            BroadcastCommand::Stop => 0b00,
+            BroadcastCommand::Start => 0b01,
+            BroadcastCommand::Reserved => 0b10,
+            BroadcastCommand::DontCare => 0b11,
+        }
+    }
+}
+
+/// Represents the networks addressed by a DM13 broadcast command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dm13Network {
+    /// The data link the DM13 is received on.
+    CurrentDataLink,
+    /// SAE J1587 network.
+    J1587,
+    /// SAE J1922 network.
+    J1922,
+    /// SAE J1939 network #1, the primary vehicle network.
+    J1939Network1,
+    /// SAE J1939 network #2.
+    J1939Network2,
+    /// ISO 9141 network.
+    Iso9141,
+    /// SAE J1850 network.
+    J1850,
+    /// Manufacturer specific port.
+    ManufacturerSpecific,
+}
+
+impl Dm13Network {
+    /// Returns the byte index and bit shift of the two-bit command for the network.
+    const fn position(self) -> (usize, u8) {
+        match self {
+            Dm13Network::CurrentDataLink => (0, 0),
+            Dm13Network::J1587 => (0, 2),
+            Dm13Network::J1922 => (0, 4),
+            Dm13Network::J1939Network1 => (0, 6),
+            Dm13Network::J1939Network2 => (1, 0),
+            Dm13Network::Iso9141 => (1, 2),
+            Dm13Network::J1850 => (1, 4),
+            Dm13Network::ManufacturerSpecific => (1, 6),
+        }
+    }
+}
+
+/// Represents the DM13 suspend signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuspendSignal {
+    /// Indefinite suspension of all broadcasts.
+    IndefiniteAll,
+    /// Indefinite suspension of some broadcasts.
+    IndefinitePartial,
+    /// Temporary suspension of all broadcasts.
+    TemporaryAll,
+    /// Temporary suspension of some broadcasts.
+    TemporaryPartial,
+    /// Resuming previously suspended broadcasts.
+    Resuming,
+    /// Reserved value.
+    /// Contains the signal value.
+    Reserved(u8),
+    /// The suspend signal is not available.
+    NotAvailable,
+}
+
+impl From<u8> for SuspendSignal {
+    fn from(value: u8) -> Self {
+        match value & 0x0F {
+            0 => SuspendSignal::IndefiniteAll,
+            1 => SuspendSignal::IndefinitePartial,
+            2 => SuspendSignal::TemporaryAll,
+            3 => SuspendSignal::TemporaryPartial,
+            4 => SuspendSignal::Resuming,
+            15 => SuspendSignal::NotAvailable,
+            s => SuspendSignal::Reserved(s),
+        }
+    }
+}
+
+impl From<SuspendSignal> for u8 {
+    fn from(value: SuspendSignal) -> Self {
+        match value {
+            SuspendSignal::IndefiniteAll => 0,
+            SuspendSignal::IndefinitePartial => 1,
+            SuspendSignal::TemporaryAll => 2,
+            SuspendSignal::TemporaryPartial => 3,
+            SuspendSignal::Resuming => 4,
+            SuspendSignal::Reserved(s) => s & 0x0F,
+            SuspendSignal::NotAvailable => 15,
+        }
+    }
+}
+
+/// Decoder and encoder for DM13 (Stop Start Broadcast, PGN 57088).
+///
+/// | Byte | Content                                                                      |
+/// |------|------------------------------------------------------------------------------|
+/// | 1    | J1939 network #1 (bits 8-7), J1922 (bits 6-5), J1587 (bits 4-3), current data link (bits 2-1) |
+/// | 2    | Manufacturer specific (bits 8-7), J1850 (bits 6-5), ISO 9141 (bits 4-3), J1939 network #2 (bits 2-1) |
+/// | 3    | Reserved                                                                     |
+/// | 4    | Suspend signal (bits 8-5), hold signal (bits 4-1)                            |
+/// | 5-6  | Suspend duration in seconds                                                  |
+/// | 7-8  | Reserved                                                                     |
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// # fn main() -> Result<(), can_types::error::Error> {
+/// // A service tool silences every controller on the current data link for 10 seconds.
+/// let dm13_a = Dm13::new()
+///     .with_command(Dm13Network::CurrentDataLink, BroadcastCommand::Stop)
+///     .with_suspend_signal(SuspendSignal::TemporaryAll)
+///     .with_suspend_duration(10);
+/// let msg_a = dm13_a.to_message(SourceAddr::Some(249), DestinationAddr::None)?;
+///
+/// assert_eq!(IdJ1939::from_hex("18DFFFF9"), msg_a.id());
+/// assert_eq!(dm13_a, Dm13::try_from(&msg_a)?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dm13 {
+    bytes: [u8; 8],
+}
+
+impl Default for Dm13 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Dm13 {
+    /// Creates a DM13 leaving every network unchanged, with the suspend signal and duration not
+    /// available.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { bytes: [0xFF; 8] }
+    }
+
+    /// Returns the broadcast command for a network.
+    #[must_use]
+    pub fn command(&self, network: Dm13Network) -> BroadcastCommand {
+        let (index, shift) = network.position();
+
+        BroadcastCommand::from(self.bytes[index] >> shift)
+    }
+
+    /// Returns a copy of the DM13 with the given broadcast command for a network.
+    #[must_use]
+    pub fn with_command(mut self, network: Dm13Network, command: BroadcastCommand) -> Self {
+        let (index, shift) = network.position();
+
+        self.bytes[index] = self.bytes[index] & !(0b11 << shift) | u8::from(command) << shift;
+        self
+    }
+
+    /// Returns the suspend signal.
+    #[must_use]
+    pub fn suspend_signal(&self) -> SuspendSignal {
+        SuspendSignal::from(self.bytes[3] >> 4)
+    }
+
+    /// Returns a copy of the DM13 with the given suspend signal.
+    #[must_use]
+    pub fn with_suspend_signal(mut self, signal: SuspendSignal) -> Self {
+        self.bytes[3] = self.bytes[3] & 0x0F | u8::from(signal) << 4;
+        self
+    }
+
+    /// Returns the suspend duration in seconds.
+    ///
+    /// # Returns
+    /// - `Some(seconds)`: If the suspend duration is available.
+    /// - `None`: If the suspend duration is not available (`0xFFFF`).
+    #[must_use]
+    pub fn suspend_duration(&self) -> Option<u16> {
+        match u16::from_le_bytes([self.bytes[4], self.bytes[5]]) {
+            0xFFFF => None,
+            seconds => Some(seconds),
+        }
+    }
+
+    /// Returns a copy of the DM13 with the given suspend duration in seconds.
+    #[must_use]
+    pub fn with_suspend_duration(mut self, seconds: u16) -> Self {
+        [self.bytes[4], self.bytes[5]] = seconds.to_le_bytes();
+        self
+    }
+
+    /// Encodes the DM13 into a message.
+    ///
+    /// # Arguments
+    /// - `source_address`: The address of the sender.
+    /// - `destination_address`: The address of the controller to command, or
+    ///   [`DestinationAddr::None`] to command every controller (global address 255).
+    ///
+    /// # Errors
+    /// - [`Error::MissingSourceAddr`] if `source_address` is [`SourceAddr::None`].
+    pub fn to_message(
+        self,
+        source_address: SourceAddr,
+        destination_address: DestinationAddr,
+    ) -> Result<Message<J1939, Data>, Error> {
+        build_message(
+            KnownPgn::Dm13,
+            source_address,
+            destination_address,
+            self.bytes,
+        )
+    }
+}
+
+impl From<Pdu<Data>> for Dm13 {
+    fn from(value: Pdu<Data>) -> Self {
+        Self {
+            bytes: value.to_be_bytes(),
+        }
+    }
+}
+
+impl TryFrom<&Message<J1939, Data>> for Dm13 {
+    type Error = Error;
+
+    fn try_from(value: &Message<J1939, Data>) -> Result<Self, Self::Error> {
+        check_pgn(value, KnownPgn::Dm13)?;
+
+        Ok(value.pdu().into())
+    }
+}
+
+#[cfg(test)]
+mod diagnostic_tests {
+    if_alloc! {
+        use alloc::format;
+    }
+
+    use super::*;
+    use crate::protocol::j1939::{identifier::IdJ1939, transport::segment};
+
+    #[test]
+    fn test_lamps() {
+        let lamps_a = Lamps::from_bytes([0b01_00_01_11, 0b11_11_00_11]);
+
+        assert_eq!(LampStatus::On, lamps_a.mil().status());
+        assert_eq!(FlashStatus::DoNotFlash, lamps_a.mil().flash());
+        assert_eq!(LampStatus::Off, lamps_a.rsl().status());
+        assert!(lamps_a.awl().is_on());
+        assert_eq!(FlashStatus::SlowFlash, lamps_a.awl().flash());
+        assert_eq!(LampStatus::NotAvailable, lamps_a.pl().status());
+    }
+
+    #[test]
+    fn test_dm1_single_frame() -> Result<(), Error> {
+        let dm1_a = Dm1::from(Pdu::<Data>::try_from_hex("44FF6E000003FFFF")?);
+        let dm1_b = Dm1::from(Pdu::<Data>::try_from_hex("00FF00000000FFFF")?);
+
+        assert!(dm1_a.lamps().mil().is_on());
+        assert!(dm1_a.lamps().awl().is_on());
+
+        let mut dtcs_a = dm1_a.dtcs();
+
+        assert_eq!(
+            Some(Dtc::from_bytes([0x6E, 0x00, 0x00, 0x03])),
+            dtcs_a.next()
+        );
+        assert_eq!(None, dtcs_a.next());
+        assert_eq!(0, dm1_b.dtcs().count());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dm1_multi_packet() -> Result<(), Error> {
+        let payload = [0x10, 0xFF, 0xBE, 0x00, 0x02, 0x01, 0xFF, 0xFF, 0xFF, 0xFE];
+        let dm1_a = Dm1::try_new(&payload[..])?;
+        let dtcs: [Dtc; 2] = {
+            let mut dtcs = dm1_a.dtcs();
+            [dtcs.next().unwrap(), dtcs.next().unwrap()]
+        };
+
+        assert!(dm1_a.lamps().rsl().is_on());
+        assert_eq!(190, dtcs[0].spn());
+        assert_eq!(Fmi::DataErratic, dtcs[0].fmi());
+        assert_eq!(1, dtcs[0].occurrence_count());
+        assert!(!dtcs[0].conversion_method());
+        assert_eq!(0x7FFFF, dtcs[1].spn());
+        assert_eq!(Fmi::ConditionExists, dtcs[1].fmi());
+        assert_eq!(126, dtcs[1].occurrence_count());
+        assert!(dtcs[1].conversion_method());
+
+        assert_eq!(
+            Err(Error::InvalidDataLength(5)),
+            Dm1::try_new(&payload[..5])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmi() {
+        for value in 0..32 {
+            assert_eq!(value, u8::from(Fmi::from(value)));
+        }
+
+        assert_eq!(Fmi::VoltageBelowNormal, Fmi::from(4));
+        assert_eq!(Fmi::Reserved(25), Fmi::from(25));
+        assert_eq!(Fmi::DataErratic, Fmi::from(0xE2));
+    }
+
+    #[test]
+    fn test_spn_label() {
+        assert_eq!(Some("Controller #1"), spn_label(629));
+        assert_eq!(Some("Engine Speed"), spn_label(190));
+        assert_eq!(None, spn_label(0x7FFFF));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_display() {
+        let dtc_a = Dtc::from_bytes([0xBE, 0x00, 0x12, 0x7F]);
+
+        assert_eq!("SPN 190 FMI 18 OC 127", format!("{dtc_a}"));
+        assert_eq!(
+            "Data valid but below normal operating range - moderately severe level",
+            format!("{}", dtc_a.fmi())
+        );
+        assert_eq!(
+            "Reserved for SAE assignment",
+            format!("{}", Fmi::Reserved(22))
+        );
+    }
+
+    #[test]
+    fn test_encode_dtc_message() -> Result<(), Error> {
+        let awl_on = Lamp::new(LampStatus::On, FlashStatus::DoNotFlash);
+        let off = Lamp::new(LampStatus::Off, FlashStatus::DoNotFlash);
+        let lamps_a = Lamps::new(off, off, awl_on, off);
+        let dtc_a = Dtc::new(110, Fmi::DataAboveNormalMostSevere, 3)?;
+
+        assert_eq!([0x04, 0xFF], lamps_a.to_bytes());
+        assert_eq!(lamps_a, Lamps::from_bytes(lamps_a.to_bytes()));
+        assert_eq!(dtc_a, Dtc::from_bytes(dtc_a.to_bytes()));
+        assert_eq!(
+            Err(Error::FieldOutOfRange {
+                field: "spn",
+                value: 0x8_0000,
+                max: 0x7_FFFF
+            }),
+            Dtc::new(0x8_0000, Fmi::DataErratic, 0)
+        );
+
+        let msg_a = Dm1::new(lamps_a, Some(dtc_a)).to_message(SourceAddr::Some(0))?;
+        let msg_b = Dm2::new(lamps_a, None).to_message(SourceAddr::Some(0))?;
+        let msg_c = Dm12::new(lamps_a, Some(dtc_a)).to_message(SourceAddr::Some(61))?;
+
+        assert_eq!(IdJ1939::from_hex("18FECA00"), msg_a.id());
+        assert_eq!(Pdu::<Data>::from_hex("04FF6E000003FFFF"), msg_a.pdu());
+        assert_eq!(IdJ1939::from_hex("18FECB00"), msg_b.id());
+        assert_eq!(Pdu::<Data>::from_hex("04FF00000000FFFF"), msg_b.pdu());
+        assert_eq!(IdJ1939::from_hex("18FED43D"), msg_c.id());
+
+        assert_eq!(Some(dtc_a), Dm1::try_from(&msg_a)?.dtcs().next());
+        assert_eq!(0, Dm2::try_from(&msg_b)?.dtcs().count());
+        assert_eq!(Some(dtc_a), Dm12::try_from(&msg_c)?.dtcs().next());
+        assert_eq!(Err(Error::UnexpectedPgn(65226)), Dm2::try_from(&msg_a));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dtc_reserved_fmi() -> Result<(), Error> {
+        for value in 22..=30 {
+            let dtc_a = Dtc::new(0x7_FFFF, Fmi::Reserved(value), 5)?;
+
+            assert_eq!(dtc_a, Dtc::from_bytes(dtc_a.to_bytes()));
+            assert_eq!(0xE0 | value, dtc_a.to_bytes()[2]);
+        }
+
+        for value in [0, 3, 21, 31, 0x25, 0xE5] {
+            assert_eq!(
+                Err(Error::FieldOutOfRange {
+                    field: "fmi",
+                    value: value.into(),
+                    max: 30
+                }),
+                Dtc::new(0, Fmi::Reserved(value), 0)
+            );
+        }
+
+        // The FMI never spills into the SPN bits.
+        let dtc_b = Dtc {
+            spn: 0,
+            fmi: Fmi::Reserved(0xE5),
+            occurrence_count: 0,
+            conversion_method: false,
+        };
+        assert_eq!([0x00, 0x00, 0x05, 0x00], dtc_b.to_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_multiple_dtcs() -> Result<(), Error> {
+        let off = Lamp::new(LampStatus::Off, FlashStatus::DoNotFlash);
+        let rsl_on = Lamp::new(LampStatus::On, FlashStatus::DoNotFlash);
+        let lamps_a = Lamps::new(off, rsl_on, off, off);
+        let dtcs = [
+            Dtc::new(190, Fmi::DataErratic, 1)?,
+            Dtc::new(110, Fmi::DataAboveNormalMostSevere, 3)?,
+        ];
+        let mut buffer = [0xAA; 16];
+
+        let dm2_a = Dm2::encode_into(lamps_a, &dtcs, &mut buffer)?;
+
+        assert_eq!(
+            [0x10, 0xFF, 0xBE, 0x00, 0x02, 0x01, 0x6E, 0x00, 0x00, 0x03],
+            dm2_a.as_bytes()
+        );
+        assert_eq!(lamps_a, dm2_a.lamps());
+        assert!(dm2_a.dtcs().eq(dtcs));
+
+        let segments = segment(
+            KnownPgn::Dm2.pgn(),
+            SourceAddr::Some(0),
+            DestinationAddr::None,
+            dm2_a.as_bytes(),
+        )?;
+
+        // BAM announcement followed by two data transfer packets.
+        assert_eq!(3, segments.len());
+
+        let mut buffer = [0; 6];
+        let dm12_a = Dm12::encode_into(lamps_a, &[], &mut buffer)?;
+
+        assert_eq!([0x10, 0xFF, 0x00, 0x00, 0x00, 0x00], dm12_a.as_bytes());
+        assert_eq!(0, dm12_a.dtcs().count());
+        assert_eq!(
+            Err(Error::InvalidDataLength(6)),
+            Dm12::encode_into(lamps_a, &dtcs, &mut buffer)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_diagnostic_request() -> Result<(), Error> {
+        let msg_a =
+            DiagnosticRequest::Dm2.to_message(SourceAddr::Some(249), DestinationAddr::None)?;
+        let msg_b =
+            DiagnosticRequest::Dm3.to_message(SourceAddr::Some(249), DestinationAddr::Some(0))?;
+        let msg_c = Message::<J1939, Data>::from_hex("18EA00F9", "EEFE00FFFFFFFFFF");
+
+        assert_eq!(IdJ1939::from_hex("18EAFFF9"), msg_a.id());
+        assert_eq!(Pdu::<Data>::from_hex("CBFE00FFFFFFFFFF"), msg_a.pdu());
+        assert_eq!(6, msg_b.id().priority());
+        assert_eq!(
+            DestinationAddr::Some(0),
+            msg_b.id().pgn().destination_address()
+        );
+        assert_eq!(DiagnosticRequest::Dm2, DiagnosticRequest::try_from(&msg_a)?);
+        assert_eq!(DiagnosticRequest::Dm3, DiagnosticRequest::try_from(&msg_b)?);
+        assert_eq!(
+            Err(Error::UnexpectedPgn(65262)),
+            DiagnosticRequest::try_from(&msg_c)
+        );
+        assert_eq!(
+            Err(Error::MissingSourceAddr),
+            DiagnosticRequest::Dm11.to_message(SourceAddr::None, DestinationAddr::None)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dm13() -> Result<(), Error> {
+        let dm13_a = Dm13::new()
+            .with_command(Dm13Network::J1939Network1, BroadcastCommand::Stop)
+            .with_command(Dm13Network::J1939Network2, BroadcastCommand::Start)
+            .with_suspend_signal(SuspendSignal::TemporaryPartial)
+            .with_suspend_duration(0x0102);
+        let msg_a = dm13_a.to_message(SourceAddr::Some(249), DestinationAddr::Some(0))?;
+
+        assert_eq!(IdJ1939::from_hex("18DF00F9"), msg_a.id());
+        assert_eq!(Pdu::<Data>::from_hex("3FFDFF3F0201FFFF"), msg_a.pdu());
+        assert_eq!(
+            BroadcastCommand::Stop,
+            dm13_a.command(Dm13Network::J1939Network1)
+        );
+        assert_eq!(
+            BroadcastCommand::Start,
+            dm13_a.command(Dm13Network::J1939Network2)
+        );
+        assert_eq!(
+            BroadcastCommand::DontCare,
+            dm13_a.command(Dm13Network::J1587)
+        );
+        assert_eq!(SuspendSignal::TemporaryPartial, dm13_a.suspend_signal());
+        assert_eq!(Some(0x0102), dm13_a.suspend_duration());
+        assert_eq!(None, Dm13::default().suspend_duration());
+        assert_eq!(dm13_a, Dm13::try_from(&msg_a)?);
+
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@ use bitfield_struct::bitfield;
 
 use crate::{
     conversion::Conversion,
+    error::Error,
     identifier::{Id, IsProtocol},
 };
 
@@ -52,12 +53,14 @@ pub struct J1939 {
     source_address_bits: u8,
 }
 
-impl IsProtocol for J1939 {}
+impl IsProtocol for J1939 {
+    type Bits = u32;
+}
 
 pub type IdJ1939 = Id<J1939>;
 
 impl Conversion<u32> for IdJ1939 {
-    type Error = anyhow::Error;
+    type Error = Error;
 
     /// Creates a new 29-bit J1939 identifier from a 32-bit integer.
     ///
@@ -113,10 +116,10 @@ impl Conversion<u32> for IdJ1939 {
     #[inline]
     fn try_from_bits(bits: u32) -> Result<Self, Self::Error> {
         if bits > 0x1FFF_FFFF {
-            return Err(anyhow::anyhow!(
-                "Identifier bits out of range! Valid range is 0..536870911 - got {}",
-                bits
-            ));
+            return Err(Error::IdBitsOutOfRange {
+                bits,
+                max: 0x1FFF_FFFF,
+            });
         }
         let bitfield = J1939(bits);
 
@@ -140,12 +143,12 @@ impl Conversion<u32> for IdJ1939 {
     /// ```
     #[inline]
     fn try_from_hex(hex_str: &str) -> Result<Self, Self::Error> {
-        let bits = u32::from_str_radix(hex_str, 16).map_err(anyhow::Error::msg)?;
+        let bits = u32::from_str_radix(hex_str, 16)?;
         if bits > 0x1FFF_FFFF {
-            return Err(anyhow::anyhow!(
-                "Identifier bits out of range! Valid range is 0x00000000..0x1FFFFFFF - got {:#08X}",
-                bits
-            ));
+            return Err(Error::IdBitsOutOfRange {
+                bits,
+                max: 0x1FFF_FFFF,
+            });
         }
         let bitfield = J1939(bits);
 
@@ -221,7 +224,7 @@ impl IdJ1939 {
     /// - `source_addr`: `u8`.
     ///
     /// # Errors
-    /// - If priority value is invalid
+    /// - [`Error::InvalidPriority`] if priority value is invalid
     ///
     /// # Examples
     /// ```rust
@@ -239,12 +242,9 @@ impl IdJ1939 {
         pdu_format: u8,
         pdu_specific: u8,
         source_addr: u8,
-    ) -> Result<Self, anyhow::Error> {
+    ) -> Result<Self, Error> {
         if priority > 0x7 {
-            return Err(anyhow::anyhow!(
-                "Invalid priority! The priority value must be between 0 and 7 inclusive - got {}.",
-                priority
-            ));
+            return Err(Error::InvalidPriority(priority));
         }
 
         let bitfield = J1939::new()
@@ -301,12 +301,84 @@ impl IdJ1939 {
     pub fn source_address(&self) -> SourceAddr {
         SourceAddr::Some(self.0.source_address_bits())
     }
+
+    /// Returns a copy of the identifier with the given priority (0 = highest priority).
+    ///
+    /// # Errors
+    /// - [`Error::InvalidPriority`] if the priority exceeds 7.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let id_a = IdJ1939::from_hex("18FEF200");
+    ///
+    /// assert_eq!(IdJ1939::from_hex("0CFEF200"), id_a.with_priority(3).unwrap());
+    /// assert!(id_a.with_priority(8).is_err());
+    /// ```
+    #[inline]
+    pub const fn with_priority(self, priority: u8) -> Result<Self, Error> {
+        if priority > 0x7 {
+            return Err(Error::InvalidPriority(priority));
+        }
+
+        Ok(Self(self.0.with_priority_bits(priority)))
+    }
+
+    /// Returns a copy of the identifier with the given reserved flag.
+    #[inline]
+    #[must_use]
+    pub const fn with_reserved(self, reserved: bool) -> Self {
+        Self(self.0.with_reserved_bits(reserved))
+    }
+
+    /// Returns a copy of the identifier with the given data page flag.
+    #[inline]
+    #[must_use]
+    pub const fn with_data_page(self, data_page: bool) -> Self {
+        Self(self.0.with_data_page_bits(data_page))
+    }
+
+    /// Returns a copy of the identifier with the given PDU format bits.
+    #[inline]
+    #[must_use]
+    pub const fn with_pdu_format(self, pdu_format: u8) -> Self {
+        Self(self.0.with_pdu_format_bits(pdu_format))
+    }
+
+    /// Returns a copy of the identifier with the given PDU specific bits.
+    #[inline]
+    #[must_use]
+    pub const fn with_pdu_specific(self, pdu_specific: u8) -> Self {
+        Self(self.0.with_pdu_specific_bits(pdu_specific))
+    }
+
+    /// Returns a copy of the identifier with the given source address.
+    ///
+    /// # Errors
+    /// - [`Error::MissingSourceAddr`] if the source address is [`SourceAddr::None`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let id_a = IdJ1939::from_hex("0CF00400");
+    ///
+    /// assert_eq!(IdJ1939::from_hex("0CF00401"), id_a.with_source_address(SourceAddr::Some(1)).unwrap());
+    /// ```
+    #[inline]
+    pub const fn with_source_address(self, source_address: SourceAddr) -> Result<Self, Error> {
+        match source_address {
+            SourceAddr::Some(sa) => Ok(Self(self.0.with_source_address_bits(sa))),
+            SourceAddr::None => Err(Error::MissingSourceAddr),
+        }
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::unusual_byte_groupings)]
 mod j1939_tests {
 
     use super::*;
+    use crate::protocol::j1939::address::DestinationAddr;
 
     #[test]
     fn test_from_bits() {
@@ -354,4 +426,39 @@ mod j1939_tests {
 
         assert_eq!("00FF00FF", id_a.into_hex())
     }
+
+    #[test]
+    fn test_setters() -> Result<(), Error> {
+        let id_a = IdJ1939::try_from_hex("0C00290B")?;
+
+        let id_b = id_a
+            .with_priority(6)?
+            .with_source_address(SourceAddr::Some(0xF9))?
+            .with_destination_address(DestinationAddr::Some(0x00))?;
+
+        assert_eq!(IdJ1939::try_from_hex("180000F9")?, id_b);
+        assert_eq!(
+            IdJ1939::try_from_hex("0C00FF0B")?,
+            id_a.with_destination_address(DestinationAddr::None)?
+        );
+        assert_eq!(
+            IdJ1939::try_from_hex("0DFEF20B")?,
+            id_a.with_data_page(true)
+                .with_pdu_format(0xFE)
+                .with_pdu_specific(0xF2)
+        );
+        assert!(id_a.with_reserved(true).reserved());
+
+        assert_eq!(Err(Error::InvalidPriority(8)), id_a.with_priority(8));
+        assert_eq!(
+            Err(Error::MissingSourceAddr),
+            id_a.with_source_address(SourceAddr::None)
+        );
+        assert_eq!(
+            Err(Error::UnexpectedDestinationAddr(65266)),
+            IdJ1939::try_from_hex("18FEF200")?.with_destination_address(DestinationAddr::Some(0))
+        );
+
+        Ok(())
+    }
 }
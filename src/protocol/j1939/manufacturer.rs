@@ -0,0 +1,200 @@
+// Copyright (c) 2024 Nathan H. Keough
+//
+// This work is dual-licensed under MIT OR Apache 2.0 (or any later version).
+// You may choose between one of them if you use this work.
+//
+// For further detail, please refer to the individual licenses located at the root of this crate.
+
+//! # J1939 Manufacturer Codes
+//!
+//! **Description:**
+//! The 11-bit manufacturer code of a NAME identifies the company responsible for the controller
+//! application. Codes are assigned by SAE and shared with NMEA 2000, which uses the same NAME
+//! layout. [`MANUFACTURERS`] maps a subset of the assigned codes to company names; codes that are
+//! not listed are not translated.
+//!
+//! Requires the `manufacturer-db` feature.
+//!
+//! **Source Documents:**
+//! - *SAE J1939 Appendix B, Table B10*
+//! - *NMEA 2000 Manufacturer Codes list*
+
+use core::fmt::{self, Display, Formatter};
+
+use crate::payload::{Name, Pdu};
+
+/// Represents a company identified by a J1939 manufacturer code.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// let name_a = NameBuilder::new().manufacturer_code(229).build().unwrap();
+/// let manufacturer = name_a.lookup_manufacturer().unwrap();
+///
+/// assert_eq!(229, manufacturer.code());
+/// assert_eq!("Garmin", manufacturer.name());
+/// assert_eq!(None, Manufacturer::lookup(2000));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Manufacturer {
+    code: u16,
+    name: &'static str,
+}
+
+/// SAE-assigned manufacturer codes, sorted by code.
+#[rustfmt::skip]
+pub static MANUFACTURERS: [Manufacturer; 49] = [
+    Manufacturer::new(69, "ARKS Enterprises"),
+    Manufacturer::new(78, "FW Murphy/Enovation Controls"),
+    Manufacturer::new(80, "Twin Disc"),
+    Manufacturer::new(85, "Kohler Power Systems"),
+    Manufacturer::new(88, "Hemisphere GPS"),
+    Manufacturer::new(135, "Airmar"),
+    Manufacturer::new(137, "Maretron"),
+    Manufacturer::new(140, "Lowrance"),
+    Manufacturer::new(144, "Mercury Marine"),
+    Manufacturer::new(147, "Nautibus Electronic"),
+    Manufacturer::new(148, "Blue Water Data"),
+    Manufacturer::new(154, "Westerbeke"),
+    Manufacturer::new(163, "Evinrude/BRP"),
+    Manufacturer::new(165, "CPAC Systems"),
+    Manufacturer::new(168, "Xantrex Technology"),
+    Manufacturer::new(172, "Yanmar Marine"),
+    Manufacturer::new(174, "Volvo Penta"),
+    Manufacturer::new(175, "Honda Marine"),
+    Manufacturer::new(176, "Carling Technologies"),
+    Manufacturer::new(185, "Beede Instruments"),
+    Manufacturer::new(192, "Floscan Instrument"),
+    Manufacturer::new(198, "Mystic Valley Communications"),
+    Manufacturer::new(199, "Actia"),
+    Manufacturer::new(228, "ZF Marine"),
+    Manufacturer::new(229, "Garmin"),
+    Manufacturer::new(257, "Honda Motor Company"),
+    Manufacturer::new(273, "Actisense"),
+    Manufacturer::new(275, "Navico"),
+    Manufacturer::new(355, "Mastervolt"),
+    Manufacturer::new(358, "Victron Energy"),
+    Manufacturer::new(370, "Rolls-Royce Marine"),
+    Manufacturer::new(374, "Northern Lights"),
+    Manufacturer::new(381, "B&G"),
+    Manufacturer::new(419, "Fusion Electronics"),
+    Manufacturer::new(440, "Cummins"),
+    Manufacturer::new(443, "VDO (Continental)"),
+    Manufacturer::new(1850, "Teleflex Marine (SeaStar Solutions)"),
+    Manufacturer::new(1851, "Raymarine"),
+    Manufacturer::new(1852, "Navionics"),
+    Manufacturer::new(1853, "Japan Radio Co"),
+    Manufacturer::new(1854, "Northstar Technologies"),
+    Manufacturer::new(1855, "Furuno"),
+    Manufacturer::new(1856, "Trimble"),
+    Manufacturer::new(1857, "Simrad"),
+    Manufacturer::new(1858, "Litton"),
+    Manufacturer::new(1859, "Kvasar AB"),
+    Manufacturer::new(1860, "MMP"),
+    Manufacturer::new(1861, "Vector Cantech"),
+    Manufacturer::new(1862, "Yamaha Marine"),
+];
+
+impl Manufacturer {
+    /// Creates a new manufacturer entry.
+    #[must_use]
+    const fn new(code: u16, name: &'static str) -> Self {
+        Self { code, name }
+    }
+
+    /// Looks up a manufacturer by its 11-bit code.
+    ///
+    /// # Returns
+    /// - `Some(Manufacturer)`: If the code is listed in [`MANUFACTURERS`].
+    /// - `None`: If the code is not listed.
+    #[must_use]
+    pub fn lookup(code: u16) -> Option<Self> {
+        MANUFACTURERS
+            .binary_search_by_key(&code, |manufacturer| manufacturer.code)
+            .ok()
+            .map(|index| MANUFACTURERS[index])
+    }
+
+    /// Returns the manufacturer code.
+    #[inline]
+    #[must_use]
+    pub const fn code(&self) -> u16 {
+        self.code
+    }
+
+    /// Returns the company name.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl Display for Manufacturer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Pdu<Name> {
+    /// Lookup and translate the manufacturer code of the NAME.
+    ///
+    /// # Returns
+    /// - `Some(Manufacturer)`: If the manufacturer code is known.
+    /// - `None`: If the manufacturer code is not listed.
+    #[inline]
+    #[must_use]
+    pub fn lookup_manufacturer(&self) -> Option<Manufacturer> {
+        Manufacturer::lookup(self.manufacturer_code())
+    }
+}
+
+#[cfg(test)]
+mod manufacturer_tests {
+    if_alloc! {
+        use alloc::format;
+    }
+
+    use super::*;
+    use crate::conversion::Conversion;
+
+    #[test]
+    fn test_table_sorted() {
+        assert!(MANUFACTURERS
+            .windows(2)
+            .all(|pair| pair[0].code < pair[1].code));
+        assert!(MANUFACTURERS.iter().all(|m| m.code <= 0x7FF));
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(
+            Some("Raymarine"),
+            Manufacturer::lookup(1851).map(|m| m.name())
+        );
+        assert_eq!(None, Manufacturer::lookup(0));
+        assert_eq!(Some("Actia"), Manufacturer::lookup(199).map(|m| m.name()));
+        assert_eq!(
+            Some("Actisense"),
+            Manufacturer::lookup(273).map(|m| m.name())
+        );
+        assert_eq!(Some("Cummins"), Manufacturer::lookup(440).map(|m| m.name()));
+
+        let name_a = Pdu::<Name>::from_hex("0000000030E00000");
+        assert_eq!(391, name_a.manufacturer_code());
+        assert_eq!(None, name_a.lookup_manufacturer());
+
+        let name_b = Pdu::<Name>::from_hex("00000000E7600000");
+        assert_eq!(1851, name_b.manufacturer_code());
+        assert_eq!(Manufacturer::lookup(1851), name_b.lookup_manufacturer());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "Volvo Penta",
+            format!("{}", Manufacturer::lookup(174).unwrap())
+        );
+    }
+}
@@ -8,5 +8,12 @@
 //! A higher-layer protocol, designed for heavy-duty vehicles and off-road equipment.
 
 pub mod address;
+pub mod diagnostic;
 pub mod identifier;
+#[cfg(feature = "manufacturer-db")]
+pub mod manufacturer;
+pub mod name;
+pub mod network;
 pub mod pgn;
+pub mod spn;
+pub mod transport;
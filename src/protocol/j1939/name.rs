@@ -0,0 +1,447 @@
+// Copyright (c) 2024 Nathan H. Keough
+//
+// This work is dual-licensed under MIT OR Apache 2.0 (or any later version).
+// You may choose between one of them if you use this work.
+//
+// For further detail, please refer to the individual licenses located at the root of this crate.
+
+//! # J1939 NAME Field Lookups
+//!
+//! **Description:**
+//! The industry group, vehicle system and function fields of a NAME are codes assigned by SAE
+//! J1939. The meaning of a vehicle system depends on the industry group, and functions 128 to 253
+//! depend on both the industry group and the vehicle system, while functions 0 to 127 mean the
+//! same thing in every industry group.
+//!
+//! [`IndustryGroup`], [`VehicleSystem`] and [`Function`] translate these codes, and
+//! [`Pdu<Name>`] implements [`Display`] to describe the controller application it identifies,
+//! such as "Engine #1 on On-Highway Tractor". Codes missing from the tables are displayed as
+//! numbers.
+//!
+//! **Source Documents:**
+//! - *SAE J1939 Appendix B*
+
+use core::fmt::{self, Display, Formatter};
+
+use crate::payload::{Name, Pdu};
+
+/// Represents the industry group of a NAME.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndustryGroup {
+    /// Global, applies to all industry groups
+    Global,
+    /// On-Highway Equipment
+    OnHighway,
+    /// Agricultural and Forestry Equipment
+    Agricultural,
+    /// Construction Equipment
+    Construction,
+    /// Marine
+    Marine,
+    /// Industrial-Process Control-Stationary (Gen-Sets)
+    Industrial,
+    /// Reserved industry group (6-7)
+    Reserved(u8),
+}
+
+impl From<u8> for IndustryGroup {
+    fn from(value: u8) -> Self {
+        match value & 0x7 {
+            0 => IndustryGroup::Global,
+            1 => IndustryGroup::OnHighway,
+            2 => IndustryGroup::Agricultural,
+            3 => IndustryGroup::Construction,
+            4 => IndustryGroup::Marine,
+            5 => IndustryGroup::Industrial,
+            group => IndustryGroup::Reserved(group),
+        }
+    }
+}
+
+impl From<IndustryGroup> for u8 {
+    fn from(value: IndustryGroup) -> Self {
+        match value {
+            IndustryGroup::Global => 0,
+            IndustryGroup::OnHighway => 1,
+            IndustryGroup::Agricultural => 2,
+            IndustryGroup::Construction => 3,
+            IndustryGroup::Marine => 4,
+            IndustryGroup::Industrial => 5,
+            IndustryGroup::Reserved(group) => group,
+        }
+    }
+}
+
+impl Display for IndustryGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            IndustryGroup::Global => write!(f, "Global"),
+            IndustryGroup::OnHighway => write!(f, "On-Highway"),
+            IndustryGroup::Agricultural => write!(f, "Agricultural"),
+            IndustryGroup::Construction => write!(f, "Construction"),
+            IndustryGroup::Marine => write!(f, "Marine"),
+            IndustryGroup::Industrial => write!(f, "Industrial"),
+            IndustryGroup::Reserved(group) => write!(f, "Reserved({group})"),
+        }
+    }
+}
+
+/// Represents the vehicle system of a NAME, whose meaning depends on its industry group.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// let vehicle_system = VehicleSystem::new(IndustryGroup::OnHighway, 1);
+///
+/// assert_eq!(Some("Tractor"), vehicle_system.name());
+/// assert_eq!("On-Highway Tractor", format!("{vehicle_system}"));
+/// assert_eq!(None, VehicleSystem::new(IndustryGroup::OnHighway, 100).name());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VehicleSystem {
+    industry_group: IndustryGroup,
+    code: u8,
+}
+
+impl VehicleSystem {
+    /// Creates a new vehicle system from its industry group and 7-bit code.
+    #[inline]
+    #[must_use]
+    pub const fn new(industry_group: IndustryGroup, code: u8) -> Self {
+        Self {
+            industry_group,
+            code,
+        }
+    }
+
+    /// Returns the industry group of the vehicle system.
+    #[inline]
+    #[must_use]
+    pub const fn industry_group(&self) -> IndustryGroup {
+        self.industry_group
+    }
+
+    /// Returns the code of the vehicle system.
+    #[inline]
+    #[must_use]
+    pub const fn code(&self) -> u8 {
+        self.code
+    }
+
+    /// Returns the name of the vehicle system.
+    ///
+    /// # Returns
+    /// - `Some(&str)`: If the code is known for the industry group.
+    /// - `None`: If the code is not listed.
+    #[must_use]
+    pub const fn name(&self) -> Option<&'static str> {
+        let name = match (self.industry_group, self.code) {
+            (_, 0) => "Non-specific System",
+            (IndustryGroup::OnHighway, 1) => "Tractor",
+            (IndustryGroup::OnHighway, 2) => "Trailer",
+            (IndustryGroup::Agricultural, 1) => "Tractor",
+            (IndustryGroup::Agricultural, 2) => "Tillage",
+            (IndustryGroup::Agricultural, 3) => "Secondary Tillage",
+            (IndustryGroup::Agricultural, 4) => "Planters/Seeders",
+            (IndustryGroup::Agricultural, 5) => "Fertilizers",
+            (IndustryGroup::Agricultural, 6) => "Sprayers",
+            (IndustryGroup::Agricultural, 7) => "Harvesters",
+            (IndustryGroup::Agricultural, 8) => "Root Harvesters",
+            (IndustryGroup::Agricultural, 9) => "Forage",
+            (IndustryGroup::Agricultural, 10) => "Irrigation",
+            (IndustryGroup::Agricultural, 11) => "Transport/Trailer",
+            (IndustryGroup::Agricultural, 12) => "Farm Yard Operations",
+            (IndustryGroup::Agricultural, 13) => "Powered Auxiliary Devices",
+            (IndustryGroup::Agricultural, 14) => "Special Crops",
+            (IndustryGroup::Agricultural, 15) => "Earth Work",
+            (IndustryGroup::Agricultural, 16) => "Skidder",
+            (IndustryGroup::Agricultural, 17) => "Sensor Systems",
+            (IndustryGroup::Construction, 1) => "Skid Steer Loader",
+            (IndustryGroup::Construction, 2) => "Articulated Dump Truck",
+            (IndustryGroup::Construction, 3) => "Backhoe",
+            (IndustryGroup::Construction, 4) => "Crawler",
+            (IndustryGroup::Construction, 5) => "Excavator",
+            (IndustryGroup::Construction, 6) => "Forklift",
+            (IndustryGroup::Construction, 7) => "Four Wheel Drive Loader",
+            (IndustryGroup::Construction, 8) => "Grader",
+            (IndustryGroup::Marine, 10) => "System Tools",
+            (IndustryGroup::Marine, 20) => "Safety Systems",
+            (IndustryGroup::Marine, 30) => "Gateway",
+            (IndustryGroup::Marine, 40) => "Power Management and Lighting Systems",
+            (IndustryGroup::Marine, 50) => "Steering Systems",
+            (IndustryGroup::Marine, 60) => "Propulsion Systems",
+            (IndustryGroup::Marine, 70) => "Navigation Systems",
+            (IndustryGroup::Marine, 80) => "Communications Systems",
+            (IndustryGroup::Marine, 90) => "Instrumentation/General Systems",
+            (IndustryGroup::Marine, 100) => "Environmental (HVAC) Systems",
+            (IndustryGroup::Marine, 110) => "Deck, Cargo and Fishing Equipment Systems",
+            _ => return None,
+        };
+
+        Some(name)
+    }
+}
+
+impl Display for VehicleSystem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{} {name}", self.industry_group),
+            None => write!(f, "{} Vehicle System {}", self.industry_group, self.code),
+        }
+    }
+}
+
+/// Represents the function of a NAME.
+///
+/// Functions 0 to 127 are independent of the industry group; functions 128 to 253 depend on the
+/// industry group and the vehicle system.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// use can_types::protocol::j1939::name::Function;
+///
+/// let tractor = VehicleSystem::new(IndustryGroup::OnHighway, 1);
+///
+/// assert_eq!(Some("Engine"), Function::new(tractor, 0).name());
+/// assert_eq!(Some("Fifth Wheel Smart System"), Function::new(tractor, 129).name());
+///
+/// let marine = VehicleSystem::new(IndustryGroup::Marine, 0);
+/// assert_eq!("Function 129", format!("{}", Function::new(marine, 129)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Function {
+    vehicle_system: VehicleSystem,
+    code: u8,
+}
+
+impl Function {
+    /// Creates a new function from its vehicle system and 8-bit code.
+    #[inline]
+    #[must_use]
+    pub const fn new(vehicle_system: VehicleSystem, code: u8) -> Self {
+        Self {
+            vehicle_system,
+            code,
+        }
+    }
+
+    /// Returns the vehicle system of the function.
+    #[inline]
+    #[must_use]
+    pub const fn vehicle_system(&self) -> VehicleSystem {
+        self.vehicle_system
+    }
+
+    /// Returns the code of the function.
+    #[inline]
+    #[must_use]
+    pub const fn code(&self) -> u8 {
+        self.code
+    }
+
+    /// Returns the name of the function.
+    ///
+    /// # Returns
+    /// - `Some(&str)`: If the code is known for the industry group and vehicle system.
+    /// - `None`: If the code is not listed.
+    #[must_use]
+    pub const fn name(&self) -> Option<&'static str> {
+        let name = match self.code {
+            0 => "Engine",
+            1 => "Auxiliary Power Unit (APU)",
+            2 => "Electric Propulsion Control",
+            3 => "Transmission",
+            4 => "Battery Pack Monitor",
+            5 => "Shift Control/Console",
+            6 => "Power TakeOff - (Main or Rear)",
+            7 => "Axle - Steering",
+            8 => "Axle - Drive",
+            9 => "Brakes - System Controller",
+            10 => "Brakes - Steer Axle",
+            11 => "Brakes - Drive Axle",
+            12 => "Retarder - Engine",
+            13 => "Retarder - Driveline",
+            14 => "Cruise Control",
+            15 => "Fuel System",
+            16 => "Steering Controller",
+            17 => "Suspension - Steer Axle",
+            18 => "Suspension - Drive Axle",
+            19 => "Instrument Cluster",
+            20 => "Trip Recorder",
+            21 => "Cab Climate Control",
+            22 => "Aerodynamic Control",
+            23 => "Vehicle Navigation",
+            24 => "Vehicle Security",
+            25 => "Network Interconnect ECU",
+            26 => "Body Controller",
+            27 => "Power TakeOff - (Secondary or Front)",
+            28 => "Off Vehicle Gateway",
+            29 => "Virtual Terminal (in cab)",
+            30 => "Management Computer",
+            31 => "Propulsion Battery Charger",
+            32 => "Headway Controller",
+            33 => "System Monitor",
+            34 => "Hydraulic Pump Controller",
+            35 => "Suspension - System Controller",
+            36 => "Pneumatic - System Controller",
+            37 => "Cab Controller",
+            38 => "Tire Pressure Control",
+            39 => "Ignition Control Module",
+            40 => "Seat Control",
+            41 => "Lighting - Operator Controls",
+            42 => "Water Pump Control",
+            43 => "Transmission Display",
+            44 => "Exhaust Emission Control",
+            45 => "Vehicle Dynamic Stability Control",
+            46 => "Oil Sensor Unit",
+            47 => "Information System Controller",
+            48 => "Ramp Control",
+            49 => "Clutch/Converter Control",
+            50 => "Auxiliary Heater",
+            51 => "Forward-Looking Collision Warning System",
+            52 => "Chassis Controller",
+            53 => "Alternator/Charging System",
+            54 => "Communications Unit, Cellular",
+            55 => "Communications Unit, Satellite",
+            56 => "Communications Unit, Radio",
+            57 => "Steering Column Unit",
+            58 => "Fan Drive Control",
+            59 => "Starter",
+            60 => "Cab Display",
+            61 => "File Server/Printer",
+            62 => "On-Board Diagnostic Unit",
+            63 => "Engine Valve Controller",
+            255 => "Not Available",
+            code => match (
+                self.vehicle_system.industry_group,
+                self.vehicle_system.code,
+                code,
+            ) {
+                (IndustryGroup::OnHighway, 1, 128) => "Forward Road Image Processor",
+                (IndustryGroup::OnHighway, 1, 129) => "Fifth Wheel Smart System",
+                (IndustryGroup::OnHighway, 1, 130) => "Catalyst Fluid Sensor",
+                (IndustryGroup::OnHighway, 1, 131) => "Adaptive Front Lighting System",
+                (IndustryGroup::OnHighway, 2, 128) => "Trailer Bridge",
+                _ => return None,
+            },
+        };
+
+        Some(name)
+    }
+}
+
+impl Display for Function {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "Function {}", self.code),
+        }
+    }
+}
+
+impl Pdu<Name> {
+    /// Returns the industry group of the NAME.
+    #[inline]
+    #[must_use]
+    pub fn lookup_industry_group(&self) -> IndustryGroup {
+        self.industry_group().into()
+    }
+
+    /// Returns the vehicle system of the NAME, within its industry group.
+    #[inline]
+    #[must_use]
+    pub fn lookup_vehicle_system(&self) -> VehicleSystem {
+        VehicleSystem::new(self.lookup_industry_group(), self.vehicle_system())
+    }
+
+    /// Returns the function of the NAME, within its industry group and vehicle system.
+    #[inline]
+    #[must_use]
+    pub fn lookup_function(&self) -> Function {
+        Function::new(self.lookup_vehicle_system(), self.function())
+    }
+}
+
+/// Describes the controller application identified by the NAME, such as
+/// "Engine #1 on On-Highway Tractor". Function instances are numbered from 1.
+impl Display for Pdu<Name> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} #{} on {}",
+            self.lookup_function(),
+            self.function_instance() + 1,
+            self.lookup_vehicle_system()
+        )
+    }
+}
+
+#[cfg(test)]
+mod name_tests {
+    if_alloc! {
+        use alloc::format;
+    }
+
+    use super::*;
+    use crate::{error::Error, payload::NameBuilder};
+
+    #[test]
+    fn test_lookup() -> Result<(), Error> {
+        let name_a = NameBuilder::new()
+            .industry_group(1)
+            .vehicle_system(1)
+            .function(0)
+            .build()?;
+
+        assert_eq!(IndustryGroup::OnHighway, name_a.lookup_industry_group());
+        assert_eq!(Some("Tractor"), name_a.lookup_vehicle_system().name());
+        assert_eq!(Some("Engine"), name_a.lookup_function().name());
+
+        assert_eq!(IndustryGroup::Reserved(7), IndustryGroup::from(7));
+        assert_eq!(4, u8::from(IndustryGroup::Marine));
+
+        // The same vehicle system code means different things per industry group.
+        assert_eq!(
+            Some("Articulated Dump Truck"),
+            VehicleSystem::new(IndustryGroup::Construction, 2).name()
+        );
+        assert_eq!(
+            Some("Tillage"),
+            VehicleSystem::new(IndustryGroup::Agricultural, 2).name()
+        );
+
+        // Functions 128 and above depend on the vehicle system.
+        let trailer = VehicleSystem::new(IndustryGroup::OnHighway, 2);
+        assert_eq!(Some("Trailer Bridge"), Function::new(trailer, 128).name());
+        assert_eq!(Some("Engine"), Function::new(trailer, 0).name());
+        assert_eq!(None, Function::new(trailer, 129).name());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_display() -> Result<(), Error> {
+        let name_a = NameBuilder::new()
+            .industry_group(1)
+            .vehicle_system(1)
+            .function(0)
+            .build()?;
+        let name_b = NameBuilder::new()
+            .industry_group(3)
+            .vehicle_system(100)
+            .function(200)
+            .function_instance(2)
+            .build()?;
+
+        assert_eq!("Engine #1 on On-Highway Tractor", format!("{name_a}"));
+        assert_eq!(
+            "Function 200 #3 on Construction Vehicle System 100",
+            format!("{name_b}")
+        );
+        assert_eq!("Reserved(6)", format!("{}", IndustryGroup::Reserved(6)));
+
+        Ok(())
+    }
+}
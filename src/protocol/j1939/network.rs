@@ -0,0 +1,437 @@
+// Copyright (c) 2024 Nathan H. Keough
+//
+// This work is dual-licensed under MIT OR Apache 2.0 (or any later version).
+// You may choose between one of them if you use this work.
+//
+// For further detail, please refer to the individual licenses located at the root of this crate.
+
+//! # J1939 Network Management
+//!
+//! **Description:**
+//! Every controller application (CA) on a J1939 network is identified by a unique 64-bit NAME
+//! and must claim a source address before using it. A CA claims an address by sending Address
+//! Claimed (PGN 60928) to the global address; when two CAs claim the same address, the one with
+//! the lower NAME keeps it. The other CA claims a different address if its NAME allows arbitrary
+//! addresses, or reports that it cannot claim an address by sending Address Claimed from the null
+//! address 254.
+//!
+//! [`AddressClaimer`] implements this procedure as a sans-IO state machine: it consumes received
+//! messages and timestamps supplied by the caller, and returns the frames to send.
+//!
+//! **Source Documents:**
+//! - *SAE J1939-81*
+
+use core::time::Duration;
+
+use crate::{
+    conversion::Conversion,
+    error::Error,
+    message::Message,
+    payload::{Data, Name, Pdu},
+    protocol::j1939::{
+        address::{DestinationAddr, SourceAddr},
+        identifier::J1939,
+        pgn::{build_message, KnownPgn, Pgn},
+    },
+};
+
+/// The null address, used to send Cannot Claim Address.
+pub const NULL_ADDRESS: u8 = 254;
+
+/// Time a CA waits after sending Address Claimed before using the address.
+pub const CLAIM_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// The range of addresses a CA with an arbitrary address capable NAME falls back to.
+const SELF_CONFIGURABLE_ADDRESSES: core::ops::RangeInclusive<u8> = 128..=247;
+
+/// The address claim state of an [`AddressClaimer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClaimState {
+    /// No address was claimed yet.
+    Idle,
+    /// The address was claimed, and may still be contested until the claim timeout elapses.
+    Claiming(u8),
+    /// The address was claimed successfully.
+    Claimed(u8),
+    /// No address could be claimed.
+    CannotClaim,
+}
+
+/// Sans-IO state machine claiming a source address for a controller application.
+///
+/// Call [`start`](Self::start) to claim the preferred address, feed every received message to
+/// [`handle`](Self::handle), send every frame returned by
+/// [`poll_transmit`](Self::poll_transmit), and call [`poll`](Self::poll) periodically so that the
+/// claim completes once [`CLAIM_TIMEOUT`] elapses without contention.
+///
+/// The claimer answers a Request for Address Claimed sent to its address or to the global
+/// address, and resolves contention by NAME priority: the lower NAME keeps the address. When it
+/// loses, it claims the next free address between 128 and 247 if its NAME is arbitrary address
+/// capable, and sends Cannot Claim Address otherwise.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// # use core::time::Duration;
+/// # fn main() -> Result<(), can_types::error::Error> {
+/// let name = Pdu::<Name>::from_hex("8000000000000002");
+/// let mut claimer = AddressClaimer::new(name, SourceAddr::Some(128))?;
+///
+/// claimer.start(Duration::from_millis(0));
+///
+/// let claim = claimer.poll_transmit().unwrap();
+/// assert_eq!(IdJ1939::from_hex("18EEFF80"), claim.id());
+///
+/// // A controller with a lower NAME claims the same address; the NAME is sent least significant
+/// // byte first.
+/// let contender = Message::<J1939, Data>::from_hex("18EEFF80", "0100000000000080");
+/// claimer.handle(&contender, Duration::from_millis(100));
+///
+/// let claim = claimer.poll_transmit().unwrap();
+/// assert_eq!(IdJ1939::from_hex("18EEFF81"), claim.id());
+///
+/// claimer.poll(Duration::from_millis(400));
+///
+/// assert_eq!(SourceAddr::Some(129), claimer.address());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AddressClaimer {
+    name: Pdu<Name>,
+    preferred_address: u8,
+    state: ClaimState,
+    deadline: Duration,
+    occupied: [bool; NULL_ADDRESS as usize],
+    pending: Option<u8>,
+}
+
+impl AddressClaimer {
+    /// Creates a new claimer.
+    ///
+    /// # Arguments
+    /// - `name`: The NAME of the controller application.
+    /// - `preferred_address`: The address to claim first.
+    ///
+    /// # Errors
+    /// - [`Error::MissingSourceAddr`] if `preferred_address` is [`SourceAddr::None`].
+    /// - [`Error::FieldOutOfRange`] if `preferred_address` is the null address 254 or the global
+    ///   address 255.
+    pub fn new(name: Pdu<Name>, preferred_address: SourceAddr) -> Result<Self, Error> {
+        let SourceAddr::Some(preferred_address) = preferred_address else {
+            return Err(Error::MissingSourceAddr);
+        };
+        Error::check_field(
+            "preferred_address",
+            preferred_address.into(),
+            u32::from(NULL_ADDRESS - 1),
+        )?;
+
+        Ok(Self {
+            name,
+            preferred_address,
+            state: ClaimState::Idle,
+            deadline: Duration::ZERO,
+            occupied: [false; NULL_ADDRESS as usize],
+            pending: None,
+        })
+    }
+
+    /// Returns the NAME of the controller application.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> Pdu<Name> {
+        self.name
+    }
+
+    /// Returns the address claim state.
+    #[inline]
+    #[must_use]
+    pub const fn state(&self) -> ClaimState {
+        self.state
+    }
+
+    /// Returns the claimed address, once the claim timeout elapsed without contention.
+    ///
+    /// # Returns
+    /// - `SourceAddr::Some(address)`: If an address was claimed.
+    /// - `SourceAddr::None`: If the claim is in progress, or no address could be claimed.
+    #[inline]
+    #[must_use]
+    pub const fn address(&self) -> SourceAddr {
+        match self.state {
+            ClaimState::Claimed(address) => SourceAddr::Some(address),
+            _ => SourceAddr::None,
+        }
+    }
+
+    /// Claims the preferred address, restarting the procedure if an address was already claimed.
+    ///
+    /// Addresses claimed by other controllers in messages handled so far are skipped when
+    /// falling back to a new address.
+    pub fn start(&mut self, now: Duration) {
+        self.claim(self.preferred_address, now);
+    }
+
+    /// Claims the given address and queues Address Claimed.
+    fn claim(&mut self, address: u8, now: Duration) {
+        self.state = ClaimState::Claiming(address);
+        self.deadline = now + CLAIM_TIMEOUT;
+        self.pending = Some(address);
+    }
+
+    /// Returns the next free address to fall back to, if the NAME allows it.
+    fn next_free_address(&self) -> Option<u8> {
+        if !self.name.arbitrary_address() {
+            return None;
+        }
+
+        SELF_CONFIGURABLE_ADDRESSES
+            .clone()
+            .find(|&address| !self.occupied[usize::from(address)])
+    }
+
+    /// Returns the address claimed or being claimed.
+    const fn current_address(&self) -> Option<u8> {
+        match self.state {
+            ClaimState::Claiming(address) | ClaimState::Claimed(address) => Some(address),
+            ClaimState::Idle | ClaimState::CannotClaim => None,
+        }
+    }
+
+    /// Consumes a received message.
+    ///
+    /// Messages other than Address Claimed and Request for Address Claimed are ignored.
+    pub fn handle(&mut self, message: &Message<J1939, Data>, now: Duration) {
+        let id = message.id();
+
+        match id.pgn().lookup() {
+            Some(KnownPgn::Rqst) => {
+                let [pgn_0, pgn_1, pgn_2, ..] = message.pdu().to_be_bytes();
+                let requested = u32::from_le_bytes([pgn_0, pgn_1, pgn_2, 0]);
+                let destination_address = id.pgn().destination_address();
+
+                let addressed = match (destination_address, self.current_address()) {
+                    (DestinationAddr::Some(255), _) => true,
+                    (DestinationAddr::Some(da), Some(address)) => da == address,
+                    _ => false,
+                };
+
+                if requested == KnownPgn::AddressClaimed.pgn().into_bits()
+                    && addressed
+                    && self.state != ClaimState::Idle
+                {
+                    self.pending = Some(self.current_address().unwrap_or(NULL_ADDRESS));
+                }
+            }
+            Some(KnownPgn::AddressClaimed) => {
+                let SourceAddr::Some(source_address) = id.source_address() else {
+                    return;
+                };
+
+                if source_address >= NULL_ADDRESS {
+                    return;
+                }
+
+                let name = Pdu::<Name>::from_bits(u64::from_le_bytes(message.pdu().to_be_bytes()));
+                self.occupied[usize::from(source_address)] = name != self.name;
+
+                if self.current_address() != Some(source_address) || name == self.name {
+                    return;
+                }
+
+                if self.name.wins_arbitration_against(&name) {
+                    self.pending = Some(source_address);
+                    return;
+                }
+
+                match self.next_free_address() {
+                    Some(address) => self.claim(address, now),
+                    None => {
+                        self.state = ClaimState::CannotClaim;
+                        self.pending = Some(NULL_ADDRESS);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Completes the claim once the claim timeout elapsed without contention.
+    pub fn poll(&mut self, now: Duration) {
+        if let ClaimState::Claiming(address) = self.state {
+            if now >= self.deadline {
+                self.state = ClaimState::Claimed(address);
+            }
+        }
+    }
+
+    /// Returns the next frame to send, if any.
+    ///
+    /// The frame is Address Claimed from the claimed address, or Cannot Claim Address from the
+    /// null address 254, both sent to the global address with the NAME least significant byte
+    /// first.
+    pub fn poll_transmit(&mut self) -> Option<Message<J1939, Data>> {
+        let address = self.pending.take()?;
+
+        build_message(
+            KnownPgn::AddressClaimed,
+            SourceAddr::Some(address),
+            DestinationAddr::None,
+            self.name.into_bits().to_le_bytes(),
+        )
+        .ok()
+    }
+}
+
+/// Builds a Request for Address Claimed.
+///
+/// # Arguments
+/// - `source_address`: The address of the requester, or the null address 254 if it has not
+///   claimed an address yet.
+/// - `destination_address`: The address of the controller to query, or
+///   [`DestinationAddr::None`] to query every controller (global address 255).
+///
+/// # Errors
+/// - [`Error::MissingSourceAddr`] if `source_address` is [`SourceAddr::None`].
+pub fn request_address_claimed(
+    source_address: SourceAddr,
+    destination_address: DestinationAddr,
+) -> Result<Message<J1939, Data>, Error> {
+    let [pgn_0, pgn_1, pgn_2, _] = Pgn::from(KnownPgn::AddressClaimed)
+        .into_bits()
+        .to_le_bytes();
+
+    build_message(
+        KnownPgn::Rqst,
+        source_address,
+        destination_address,
+        [pgn_0, pgn_1, pgn_2, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+    )
+}
+
+#[cfg(test)]
+mod network_tests {
+    use super::*;
+    use crate::protocol::j1939::identifier::IdJ1939;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    fn claimed(source_address: u8, name: u64) -> Message<J1939, Data> {
+        let id = IdJ1939::from_bits(0x18EE_FF00 | u32::from(source_address));
+
+        Message::from_parts(id, Pdu::<Data>::from_bits(name.swap_bytes()))
+    }
+
+    #[test]
+    fn test_address_claim() -> Result<(), Error> {
+        let name_a = Pdu::<Name>::from_hex("0000000000000002");
+        let mut claimer = AddressClaimer::new(name_a, SourceAddr::Some(0x10))?;
+
+        assert!(AddressClaimer::new(name_a, SourceAddr::Some(254)).is_err());
+        assert_eq!(
+            Err(Error::MissingSourceAddr),
+            AddressClaimer::new(name_a, SourceAddr::None).map(|_| ())
+        );
+        assert_eq!(ClaimState::Idle, claimer.state());
+        assert!(claimer.poll_transmit().is_none());
+
+        claimer.start(ms(0));
+        let claim = claimer.poll_transmit().unwrap();
+        assert_eq!(IdJ1939::from_hex("18EEFF10"), claim.id());
+        assert_eq!(Pdu::<Data>::from_hex("0200000000000000"), claim.pdu());
+
+        claimer.poll(ms(249));
+        assert_eq!(ClaimState::Claiming(0x10), claimer.state());
+        assert_eq!(SourceAddr::None, claimer.address());
+
+        // A contender with a higher NAME loses: the claim is repeated.
+        claimer.handle(&claimed(0x10, 3), ms(100));
+        assert_eq!(
+            IdJ1939::from_hex("18EEFF10"),
+            claimer.poll_transmit().unwrap().id()
+        );
+
+        // Our own claim, echoed back, is not contention.
+        claimer.handle(&claim, ms(100));
+        assert!(claimer.poll_transmit().is_none());
+
+        claimer.poll(ms(250));
+        assert_eq!(SourceAddr::Some(0x10), claimer.address());
+
+        // Request for Address Claimed, global then to another address.
+        let request = request_address_claimed(SourceAddr::Some(0xF9), DestinationAddr::None)?;
+        assert_eq!(IdJ1939::from_hex("18EAFFF9"), request.id());
+        assert_eq!(Pdu::<Data>::from_hex("00EE00FFFFFFFFFF"), request.pdu());
+
+        claimer.handle(&request, ms(300));
+        assert_eq!(
+            IdJ1939::from_hex("18EEFF10"),
+            claimer.poll_transmit().unwrap().id()
+        );
+
+        let request = request_address_claimed(SourceAddr::Some(0xF9), DestinationAddr::Some(0x11))?;
+        claimer.handle(&request, ms(300));
+        assert!(claimer.poll_transmit().is_none());
+
+        // A contender with a lower NAME wins; the NAME is not arbitrary address capable.
+        claimer.handle(&claimed(0x10, 1), ms(400));
+        assert_eq!(ClaimState::CannotClaim, claimer.state());
+
+        let cannot_claim = claimer.poll_transmit().unwrap();
+        assert_eq!(IdJ1939::from_hex("18EEFFFE"), cannot_claim.id());
+        assert_eq!(
+            Pdu::<Data>::from_hex("0200000000000000"),
+            cannot_claim.pdu()
+        );
+
+        let request = request_address_claimed(SourceAddr::Some(0xF9), DestinationAddr::None)?;
+        claimer.handle(&request, ms(500));
+        assert_eq!(
+            IdJ1939::from_hex("18EEFFFE"),
+            claimer.poll_transmit().unwrap().id()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_arbitrary_address() -> Result<(), Error> {
+        let name_a = Pdu::<Name>::from_hex("8000000000000002");
+        let mut claimer = AddressClaimer::new(name_a, SourceAddr::Some(0x10))?;
+
+        // 128 and 129 are already taken.
+        claimer.handle(&claimed(0x80, 5), ms(0));
+        claimer.handle(&claimed(0x81, 6), ms(0));
+
+        claimer.start(ms(0));
+        claimer.poll_transmit();
+        claimer.handle(&claimed(0x10, 1), ms(20));
+        assert_eq!(ClaimState::Claiming(0x82), claimer.state());
+        assert_eq!(
+            IdJ1939::from_hex("18EEFF82"),
+            claimer.poll_transmit().unwrap().id()
+        );
+
+        claimer.poll(ms(269));
+        assert_eq!(SourceAddr::None, claimer.address());
+        claimer.poll(ms(270));
+        assert_eq!(SourceAddr::Some(0x82), claimer.address());
+
+        // Every self-configurable address is taken.
+        for address in 0x82..=0xF7 {
+            claimer.handle(&claimed(address, 1), ms(300));
+        }
+
+        assert_eq!(ClaimState::CannotClaim, claimer.state());
+        let frames = core::iter::from_fn(|| claimer.poll_transmit());
+        assert_eq!(
+            Some(IdJ1939::from_hex("18EEFFFE")),
+            frames.last().map(|frame| frame.id())
+        );
+
+        Ok(())
+    }
+}
@@ -21,8 +21,11 @@
 //!   could be used for transmission status.
 //!
 //! **Examples of PGNs:**
-//! - *PGN 61444:* Provides information on the engine temperature.
-//! - *PGN 65265:* Transmits data related to the vehicle's diagnostic information.
+//! - *PGN 61444:* Electronic Engine Controller 1 (EEC1), carrying engine speed and torque.
+//! - *PGN 65265:* Cruise Control/Vehicle Speed (CCVS), carrying wheel-based vehicle speed.
+//!
+//! Well-known parameter groups are catalogued by [`KnownPgn`] and can be found with
+//! [`Pgn::lookup`].
 //!
 //! **Source Documents:**
 //! - *SAE J1939-21*
@@ -32,11 +35,20 @@ if_alloc! {
     use crate::alloc::{string::String, fmt::format};
 }
 
+use core::fmt::{self, Display, Formatter};
+
 use bitfield_struct::bitfield;
 
-use crate::{conversion::Conversion, identifier::Id, protocol::j1939::identifier::J1939};
+use crate::{
+    conversion::Conversion,
+    error::Error,
+    identifier::Id,
+    message::Message,
+    payload::{Data, Pdu},
+    protocol::j1939::identifier::J1939,
+};
 
-use super::address::DestinationAddr;
+use super::address::{DestinationAddr, SourceAddr};
 
 /// Represents the assignment type of a Protocol Data Unit (PDU).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -109,7 +121,7 @@ pub struct Pgn {
 }
 
 impl Conversion<u32> for Pgn {
-    type Error = anyhow::Error;
+    type Error = Error;
 
     /// Creates a new [`Pgn`] bitfield from a 32-bit integer.
     #[inline]
@@ -127,13 +139,11 @@ impl Conversion<u32> for Pgn {
 
     /// Creates a new [`Pgn`] bitfield from a 32-bit integer.
     /// # Errors
-    /// - Never (conversion is trivial)
+    /// - If value out of range for valid 18-bit PGNs.
     #[inline]
     fn try_from_bits(bits: u32) -> Result<Self, Self::Error> {
         if bits > 0x3FFFF {
-            return Err(anyhow::anyhow!(
-                "PGN bits out of range! Valid range is 0x0000..0xFFFF - got {bits:#04X}"
-            ));
+            return Err(Error::PgnBitsOutOfRange(bits));
         }
         Ok(Self(bits))
     }
@@ -144,11 +154,9 @@ impl Conversion<u32> for Pgn {
     /// - If value out of range for valid 18-bit PGNs.
     #[inline]
     fn try_from_hex(hex_str: &str) -> Result<Self, Self::Error> {
-        let bits = u32::from_str_radix(hex_str, 16).map_err(anyhow::Error::msg)?;
+        let bits = u32::from_str_radix(hex_str, 16)?;
         if bits > 0x3FFFF {
-            return Err(anyhow::anyhow!(
-                "PGN bits out of range! Valid range is 0x0000..0xFFFF - got {bits:#04X}"
-            ));
+            return Err(Error::PgnBitsOutOfRange(bits));
         }
         Ok(Self(bits))
     }
@@ -274,6 +282,94 @@ impl Pgn {
             p => PduAssignment::Unknown(p),
         }
     }
+
+    /// Lookup and translate the [`Pgn`] object.
+    ///
+    /// For PDU1 (point-to-point) PGNs the PDU specific byte holds a destination address and is
+    /// ignored.
+    ///
+    /// # Returns
+    /// - `Some(KnownPgn)`: If the parameter group is catalogued.
+    /// - `None`: If the parameter group is unknown.
+    #[must_use]
+    pub fn lookup(&self) -> Option<KnownPgn> {
+        let bits = match self.pdu_format() {
+            PduFormat::Pdu1(_) => self.into_bits() & 0x0003_FF00,
+            PduFormat::Pdu2(_) => self.into_bits(),
+        };
+
+        KNOWN_PGNS
+            .iter()
+            .copied()
+            .find(|known| known.pgn().0 == bits)
+    }
+
+    /// Returns a copy of the PGN with the given reserved flag.
+    #[inline]
+    #[must_use]
+    pub const fn with_reserved(self, reserved: bool) -> Self {
+        self.with_reserved_bits(reserved)
+    }
+
+    /// Returns a copy of the PGN with the given data page flag.
+    #[inline]
+    #[must_use]
+    pub const fn with_data_page(self, data_page: bool) -> Self {
+        self.with_data_page_bits(data_page)
+    }
+
+    /// Returns a copy of the PGN with the given PDU format bits.
+    #[inline]
+    #[must_use]
+    pub const fn with_pdu_format(self, pdu_format: u8) -> Self {
+        self.with_pdu_format_bits(pdu_format)
+    }
+
+    /// Returns a copy of the PGN with the given PDU specific bits.
+    #[inline]
+    #[must_use]
+    pub const fn with_pdu_specific(self, pdu_specific: u8) -> Self {
+        self.with_pdu_specific_bits(pdu_specific)
+    }
+
+    /// Returns a copy of the PGN with the given destination address in its PDU specific bits.
+    ///
+    /// [`DestinationAddr::None`] addresses the PGN to the global address (255).
+    ///
+    /// # Errors
+    /// - [`Error::UnexpectedDestinationAddr`] if a destination address is provided for a PDU2
+    ///   (broadcast) PGN.
+    #[inline]
+    pub const fn with_destination_address(
+        self,
+        destination_address: DestinationAddr,
+    ) -> Result<Self, Error> {
+        match (self.pdu_format(), destination_address) {
+            (PduFormat::Pdu1(_), DestinationAddr::Some(da)) => Ok(self.with_pdu_specific_bits(da)),
+            (PduFormat::Pdu1(_), DestinationAddr::None) => Ok(self.with_pdu_specific_bits(0xFF)),
+            (PduFormat::Pdu2(_), DestinationAddr::None) => Ok(self),
+            (PduFormat::Pdu2(_), DestinationAddr::Some(_)) => {
+                Err(Error::UnexpectedDestinationAddr(self.0))
+            }
+        }
+    }
+}
+
+/// Formats the PGN as its decimal value, followed by its acronym if it is a [`KnownPgn`].
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// assert_eq!("61444 (EEC1)", format!("{}", Pgn::from_bits(61444)));
+/// assert_eq!("65170", format!("{}", Pgn::from_bits(65170)));
+/// ```
+impl Display for Pgn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.lookup() {
+            Some(known) => write!(f, "{} ({})", self.0, known.acronym()),
+            None => write!(f, "{}", self.0),
+        }
+    }
 }
 
 impl Id<J1939> {
@@ -306,10 +402,589 @@ impl Id<J1939> {
             .with_pdu_format_bits(self.pdu_format())
             .with_pdu_specific_bits(self.pdu_specific())
     }
+
+    /// Returns a copy of the identifier with the given destination address.
+    ///
+    /// The destination address occupies the PDU specific bits of PDU1 (point-to-point) identifiers.
+    /// [`DestinationAddr::None`] addresses the message to the global address (255).
+    ///
+    /// # Errors
+    /// - [`Error::UnexpectedDestinationAddr`] if a destination address is provided for a PDU2
+    ///   (broadcast) identifier.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let id_a = IdJ1939::from_hex("0C00290B");
+    /// let id_b = IdJ1939::from_hex("18FEF200");
+    ///
+    /// assert_eq!(IdJ1939::from_hex("0C00000B"), id_a.with_destination_address(DestinationAddr::Some(0)).unwrap());
+    /// assert!(id_b.with_destination_address(DestinationAddr::Some(0)).is_err());
+    /// ```
+    #[inline]
+    pub const fn with_destination_address(
+        self,
+        destination_address: DestinationAddr,
+    ) -> Result<Self, Error> {
+        match self.pgn().with_destination_address(destination_address) {
+            Ok(pgn) => Ok(self.with_pdu_specific(pgn.pdu_specific_bits())),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Builds a 29-bit J1939 identifier from a [`Pgn`], priority, and source and destination addresses.
+///
+/// This is the encoding counterpart to [`Id::<J1939>::pgn`] and [`Pgn::destination_address`]: for
+/// PDU1 (point-to-point) PGNs the destination address is placed in the PDU specific byte, while
+/// PDU2 (broadcast) PGNs keep their group extension and reject a destination address.
+///
+/// # Defaults
+/// - `priority`: 6
+/// - `destination_address`: [`DestinationAddr::None`], which addresses PDU1 PGNs to the global
+///   address (255).
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// # fn main() -> Result<(), can_types::error::Error> {
+/// // PGN 0 (TSC1) sent by the brakes (SA 11) to the retarder (DA 41)
+/// let id_a = IdJ1939Builder::new(Pgn::try_from_bits(0)?)
+///     .priority(3)
+///     .source_address(SourceAddr::Some(11))
+///     .destination_address(DestinationAddr::Some(41))
+///     .build()?;
+///
+/// assert_eq!(IdJ1939::from_hex("0C00290B"), id_a);
+/// assert_eq!(DestinationAddr::Some(41), id_a.pgn().destination_address());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdJ1939Builder {
+    pgn: Pgn,
+    priority: u8,
+    source_address: SourceAddr,
+    destination_address: DestinationAddr,
+}
+
+impl IdJ1939Builder {
+    /// Creates a new builder for an identifier carrying the given [`Pgn`].
+    #[inline]
+    #[must_use]
+    pub const fn new(pgn: Pgn) -> Self {
+        Self {
+            pgn,
+            priority: 6,
+            source_address: SourceAddr::None,
+            destination_address: DestinationAddr::None,
+        }
+    }
+
+    /// Sets the priority of the identifier (0 = highest priority).
+    #[inline]
+    #[must_use]
+    pub const fn priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the source address of the identifier.
+    #[inline]
+    #[must_use]
+    pub const fn source_address(mut self, source_address: SourceAddr) -> Self {
+        self.source_address = source_address;
+        self
+    }
+
+    /// Sets the destination address of the identifier.
+    ///
+    /// Only PDU1 (point-to-point) PGNs accept a destination address.
+    #[inline]
+    #[must_use]
+    pub const fn destination_address(mut self, destination_address: DestinationAddr) -> Self {
+        self.destination_address = destination_address;
+        self
+    }
+
+    /// Builds the 29-bit J1939 identifier.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidPriority`] if the priority exceeds 7.
+    /// - [`Error::MissingSourceAddr`] if no source address was provided.
+    /// - [`Error::UnexpectedDestinationAddr`] if a destination address was provided for a PDU2 PGN.
+    pub fn build(self) -> Result<Id<J1939>, Error> {
+        let SourceAddr::Some(source_address) = self.source_address else {
+            return Err(Error::MissingSourceAddr);
+        };
+
+        let pgn = self
+            .pgn
+            .with_destination_address(self.destination_address)?;
+
+        Id::<J1939>::from_raw_parts(
+            self.priority,
+            pgn.reserved_bits(),
+            pgn.data_page_bits(),
+            pgn.pdu_format_bits(),
+            pgn.pdu_specific_bits(),
+            source_address,
+        )
+    }
+}
+
+/// Represents the nominal transmission rate of a parameter group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransmissionRate {
+    /// Transmitted periodically.
+    /// Contains the period in milliseconds.
+    Periodic(u32),
+    /// Transmitted at a rate that depends on engine speed.
+    EngineSpeedDependent,
+    /// Transmitted when a change of state occurs or when required by the application.
+    OnChange,
+    /// Transmitted only in response to a request.
+    OnRequest,
+}
+
+/// The [`KnownPgn`] enum represents well-known parameter groups defined by SAE J1939-21,
+/// J1939-71, J1939-73 and J1939-81. Each variant corresponds to a specific PGN together with its
+/// acronym, full name, default priority, data length and nominal transmission rate.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// let pgn_a = Pgn::from_bits(61444);
+/// let known_a = pgn_a.lookup().unwrap();
+///
+/// assert_eq!(KnownPgn::Eec1, known_a);
+/// assert_eq!("EEC1", known_a.acronym());
+/// assert_eq!(3, known_a.default_priority());
+/// assert_eq!(Some(8), known_a.data_length());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnownPgn {
+    /// Torque/Speed Control 1 | (TSC1)
+    Tsc1,
+
+    /// Diagnostic Message 13 - Stop Start Broadcast | (DM13)
+    Dm13,
+
+    /// Acknowledgment | (ACKM)
+    Ackm,
+
+    /// Request | (RQST)
+    Rqst,
+
+    /// Transport Protocol - Data Transfer | (TP.DT)
+    TpDt,
+
+    /// Transport Protocol - Connection Management | (TP.CM)
+    TpCm,
+
+    /// Address Claimed | (AC)
+    AddressClaimed,
+
+    /// Electronic Retarder Controller 1 | (ERC1)
+    Erc1,
+
+    /// Electronic Brake Controller 1 | (EBC1)
+    Ebc1,
+
+    /// Electronic Transmission Controller 1 | (ETC1)
+    Etc1,
+
+    /// Electronic Engine Controller 2 | (EEC2)
+    Eec2,
+
+    /// Electronic Engine Controller 1 | (EEC1)
+    Eec1,
+
+    /// Electronic Transmission Controller 2 | (ETC2)
+    Etc2,
+
+    /// ECU Identification Information | (ECUID)
+    Ecuid,
+
+    /// Tachograph | (TCO1)
+    Tco1,
+
+    /// Wheel Speed Information | (EBC2)
+    Ebc2,
+
+    /// Diagnostic Message 1 - Active Diagnostic Trouble Codes | (DM1)
+    Dm1,
+
+    /// Diagnostic Message 2 - Previously Active Diagnostic Trouble Codes | (DM2)
+    Dm2,
+
+    /// Diagnostic Message 3 - Diagnostic Data Clear/Reset of Previously Active DTCs | (DM3)
+    Dm3,
+
+    /// Diagnostic Message 11 - Diagnostic Data Clear/Reset for Active DTCs | (DM11)
+    Dm11,
+
+    /// Diagnostic Message 12 - Emissions-Related Active Diagnostic Trouble Codes | (DM12)
+    Dm12,
+
+    /// Commanded Address | (CA)
+    CommandedAddress,
+
+    /// Software Identification | (SOFT)
+    Soft,
+
+    /// Electronic Engine Controller 3 | (EEC3)
+    Eec3,
+
+    /// Vehicle Distance | (VD)
+    Vd,
+
+    /// Engine Hours, Revolutions | (HOURS)
+    Hours,
+
+    /// Time/Date | (TD)
+    Td,
+
+    /// Fuel Consumption (Liquid) | (LFC)
+    Lfc,
+
+    /// Component Identification | (CI)
+    Ci,
+
+    /// Vehicle Identification | (VI)
+    Vi,
+
+    /// Engine Temperature 1 | (ET1)
+    Et1,
+
+    /// Engine Fluid Level/Pressure 1 | (EFL/P1)
+    Eflp1,
+
+    /// Cruise Control/Vehicle Speed | (CCVS)
+    Ccvs,
+
+    /// Fuel Economy (Liquid) | (LFE)
+    Lfe,
+
+    /// Ambient Conditions | (AMB)
+    Amb,
+
+    /// Inlet/Exhaust Conditions 1 | (IC1)
+    Ic1,
+
+    /// Vehicle Electrical Power 1 | (VEP1)
+    Vep1,
+
+    /// Dash Display | (DD)
+    Dd,
+}
+
+/// Every catalogued parameter group, in ascending PGN order.
+const KNOWN_PGNS: [KnownPgn; 38] = [
+    KnownPgn::Tsc1,
+    KnownPgn::Dm13,
+    KnownPgn::Ackm,
+    KnownPgn::Rqst,
+    KnownPgn::TpDt,
+    KnownPgn::TpCm,
+    KnownPgn::AddressClaimed,
+    KnownPgn::Erc1,
+    KnownPgn::Ebc1,
+    KnownPgn::Etc1,
+    KnownPgn::Eec2,
+    KnownPgn::Eec1,
+    KnownPgn::Etc2,
+    KnownPgn::Ecuid,
+    KnownPgn::Tco1,
+    KnownPgn::Ebc2,
+    KnownPgn::Dm1,
+    KnownPgn::Dm2,
+    KnownPgn::Dm3,
+    KnownPgn::Dm11,
+    KnownPgn::Dm12,
+    KnownPgn::CommandedAddress,
+    KnownPgn::Soft,
+    KnownPgn::Eec3,
+    KnownPgn::Vd,
+    KnownPgn::Hours,
+    KnownPgn::Td,
+    KnownPgn::Lfc,
+    KnownPgn::Ci,
+    KnownPgn::Vi,
+    KnownPgn::Et1,
+    KnownPgn::Eflp1,
+    KnownPgn::Ccvs,
+    KnownPgn::Lfe,
+    KnownPgn::Amb,
+    KnownPgn::Ic1,
+    KnownPgn::Vep1,
+    KnownPgn::Dd,
+];
+
+impl KnownPgn {
+    /// Returns the [`Pgn`] of the parameter group.
+    ///
+    /// For PDU1 (point-to-point) parameter groups the PDU specific byte is zero.
+    #[must_use]
+    pub const fn pgn(self) -> Pgn {
+        let bits = match self {
+            KnownPgn::Tsc1 => 0,
+            KnownPgn::Dm13 => 57088,
+            KnownPgn::Ackm => 59392,
+            KnownPgn::Rqst => 59904,
+            KnownPgn::TpDt => 60160,
+            KnownPgn::TpCm => 60416,
+            KnownPgn::AddressClaimed => 60928,
+            KnownPgn::Erc1 => 61440,
+            KnownPgn::Ebc1 => 61441,
+            KnownPgn::Etc1 => 61442,
+            KnownPgn::Eec2 => 61443,
+            KnownPgn::Eec1 => 61444,
+            KnownPgn::Etc2 => 61445,
+            KnownPgn::Ecuid => 64965,
+            KnownPgn::Tco1 => 65132,
+            KnownPgn::Ebc2 => 65215,
+            KnownPgn::Dm1 => 65226,
+            KnownPgn::Dm2 => 65227,
+            KnownPgn::Dm3 => 65228,
+            KnownPgn::Dm11 => 65235,
+            KnownPgn::Dm12 => 65236,
+            KnownPgn::CommandedAddress => 65240,
+            KnownPgn::Soft => 65242,
+            KnownPgn::Eec3 => 65247,
+            KnownPgn::Vd => 65248,
+            KnownPgn::Hours => 65253,
+            KnownPgn::Td => 65254,
+            KnownPgn::Lfc => 65257,
+            KnownPgn::Ci => 65259,
+            KnownPgn::Vi => 65260,
+            KnownPgn::Et1 => 65262,
+            KnownPgn::Eflp1 => 65263,
+            KnownPgn::Ccvs => 65265,
+            KnownPgn::Lfe => 65266,
+            KnownPgn::Amb => 65269,
+            KnownPgn::Ic1 => 65270,
+            KnownPgn::Vep1 => 65271,
+            KnownPgn::Dd => 65276,
+        };
+
+        Pgn(bits)
+    }
+
+    /// Returns the acronym of the parameter group, such as `"EEC1"`.
+    #[must_use]
+    pub const fn acronym(self) -> &'static str {
+        match self {
+            KnownPgn::Tsc1 => "TSC1",
+            KnownPgn::Dm13 => "DM13",
+            KnownPgn::Ackm => "ACKM",
+            KnownPgn::Rqst => "RQST",
+            KnownPgn::TpDt => "TP.DT",
+            KnownPgn::TpCm => "TP.CM",
+            KnownPgn::AddressClaimed => "AC",
+            KnownPgn::Erc1 => "ERC1",
+            KnownPgn::Ebc1 => "EBC1",
+            KnownPgn::Etc1 => "ETC1",
+            KnownPgn::Eec2 => "EEC2",
+            KnownPgn::Eec1 => "EEC1",
+            KnownPgn::Etc2 => "ETC2",
+            KnownPgn::Ecuid => "ECUID",
+            KnownPgn::Tco1 => "TCO1",
+            KnownPgn::Ebc2 => "EBC2",
+            KnownPgn::Dm1 => "DM1",
+            KnownPgn::Dm2 => "DM2",
+            KnownPgn::Dm3 => "DM3",
+            KnownPgn::Dm11 => "DM11",
+            KnownPgn::Dm12 => "DM12",
+            KnownPgn::CommandedAddress => "CA",
+            KnownPgn::Soft => "SOFT",
+            KnownPgn::Eec3 => "EEC3",
+            KnownPgn::Vd => "VD",
+            KnownPgn::Hours => "HOURS",
+            KnownPgn::Td => "TD",
+            KnownPgn::Lfc => "LFC",
+            KnownPgn::Ci => "CI",
+            KnownPgn::Vi => "VI",
+            KnownPgn::Et1 => "ET1",
+            KnownPgn::Eflp1 => "EFL/P1",
+            KnownPgn::Ccvs => "CCVS",
+            KnownPgn::Lfe => "LFE",
+            KnownPgn::Amb => "AMB",
+            KnownPgn::Ic1 => "IC1",
+            KnownPgn::Vep1 => "VEP1",
+            KnownPgn::Dd => "DD",
+        }
+    }
+
+    /// Returns the full name of the parameter group, such as `"Electronic Engine Controller 1"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            KnownPgn::Tsc1 => "Torque/Speed Control 1",
+            KnownPgn::Dm13 => "Stop Start Broadcast",
+            KnownPgn::Ackm => "Acknowledgment",
+            KnownPgn::Rqst => "Request",
+            KnownPgn::TpDt => "Transport Protocol - Data Transfer",
+            KnownPgn::TpCm => "Transport Protocol - Connection Management",
+            KnownPgn::AddressClaimed => "Address Claimed",
+            KnownPgn::Erc1 => "Electronic Retarder Controller 1",
+            KnownPgn::Ebc1 => "Electronic Brake Controller 1",
+            KnownPgn::Etc1 => "Electronic Transmission Controller 1",
+            KnownPgn::Eec2 => "Electronic Engine Controller 2",
+            KnownPgn::Eec1 => "Electronic Engine Controller 1",
+            KnownPgn::Etc2 => "Electronic Transmission Controller 2",
+            KnownPgn::Ecuid => "ECU Identification Information",
+            KnownPgn::Tco1 => "Tachograph",
+            KnownPgn::Ebc2 => "Wheel Speed Information",
+            KnownPgn::Dm1 => "Active Diagnostic Trouble Codes",
+            KnownPgn::Dm2 => "Previously Active Diagnostic Trouble Codes",
+            KnownPgn::Dm3 => "Diagnostic Data Clear/Reset of Previously Active DTCs",
+            KnownPgn::Dm11 => "Diagnostic Data Clear/Reset for Active DTCs",
+            KnownPgn::Dm12 => "Emissions-Related Active Diagnostic Trouble Codes",
+            KnownPgn::CommandedAddress => "Commanded Address",
+            KnownPgn::Soft => "Software Identification",
+            KnownPgn::Eec3 => "Electronic Engine Controller 3",
+            KnownPgn::Vd => "Vehicle Distance",
+            KnownPgn::Hours => "Engine Hours, Revolutions",
+            KnownPgn::Td => "Time/Date",
+            KnownPgn::Lfc => "Fuel Consumption (Liquid)",
+            KnownPgn::Ci => "Component Identification",
+            KnownPgn::Vi => "Vehicle Identification",
+            KnownPgn::Et1 => "Engine Temperature 1",
+            KnownPgn::Eflp1 => "Engine Fluid Level/Pressure 1",
+            KnownPgn::Ccvs => "Cruise Control/Vehicle Speed",
+            KnownPgn::Lfe => "Fuel Economy (Liquid)",
+            KnownPgn::Amb => "Ambient Conditions",
+            KnownPgn::Ic1 => "Inlet/Exhaust Conditions 1",
+            KnownPgn::Vep1 => "Vehicle Electrical Power 1",
+            KnownPgn::Dd => "Dash Display",
+        }
+    }
+
+    /// Returns the default priority of the parameter group, between 0 (highest) and 7 (lowest).
+    #[must_use]
+    pub const fn default_priority(self) -> u8 {
+        match self {
+            KnownPgn::Tsc1 | KnownPgn::Etc1 | KnownPgn::Eec2 | KnownPgn::Eec1 | KnownPgn::Tco1 => 3,
+            KnownPgn::TpDt | KnownPgn::TpCm => 7,
+            _ => 6,
+        }
+    }
+
+    /// Returns the data length of the parameter group in bytes.
+    ///
+    /// # Returns
+    /// - `Some(length)`: If the parameter group has a fixed data length.
+    /// - `None`: If the data length is variable, such as for diagnostic messages carrying a list
+    ///   of trouble codes.
+    #[must_use]
+    pub const fn data_length(self) -> Option<u16> {
+        match self {
+            KnownPgn::Rqst => Some(3),
+            KnownPgn::Dm3 | KnownPgn::Dm11 => Some(0),
+            KnownPgn::CommandedAddress => Some(9),
+            KnownPgn::Ecuid
+            | KnownPgn::Dm1
+            | KnownPgn::Dm2
+            | KnownPgn::Dm12
+            | KnownPgn::Soft
+            | KnownPgn::Ci
+            | KnownPgn::Vi => None,
+            _ => Some(8),
+        }
+    }
+
+    /// Returns the nominal transmission rate of the parameter group.
+    #[must_use]
+    pub const fn transmission_rate(self) -> TransmissionRate {
+        match self {
+            KnownPgn::Tsc1 | KnownPgn::Etc1 => TransmissionRate::Periodic(10),
+            KnownPgn::Eec2 | KnownPgn::Tco1 => TransmissionRate::Periodic(50),
+            KnownPgn::Erc1
+            | KnownPgn::Ebc1
+            | KnownPgn::Etc2
+            | KnownPgn::Ebc2
+            | KnownPgn::Vd
+            | KnownPgn::Ccvs
+            | KnownPgn::Lfe => TransmissionRate::Periodic(100),
+            KnownPgn::Eec3 => TransmissionRate::Periodic(250),
+            KnownPgn::Eflp1 | KnownPgn::Ic1 => TransmissionRate::Periodic(500),
+            KnownPgn::Dm1 | KnownPgn::Et1 | KnownPgn::Amb | KnownPgn::Vep1 | KnownPgn::Dd => {
+                TransmissionRate::Periodic(1000)
+            }
+            KnownPgn::Eec1 => TransmissionRate::EngineSpeedDependent,
+            KnownPgn::Dm13
+            | KnownPgn::Ackm
+            | KnownPgn::Rqst
+            | KnownPgn::TpDt
+            | KnownPgn::TpCm
+            | KnownPgn::AddressClaimed
+            | KnownPgn::CommandedAddress => TransmissionRate::OnChange,
+            KnownPgn::Ecuid
+            | KnownPgn::Dm2
+            | KnownPgn::Dm3
+            | KnownPgn::Dm11
+            | KnownPgn::Dm12
+            | KnownPgn::Soft
+            | KnownPgn::Hours
+            | KnownPgn::Td
+            | KnownPgn::Lfc
+            | KnownPgn::Ci
+            | KnownPgn::Vi => TransmissionRate::OnRequest,
+        }
+    }
+}
+
+impl Display for KnownPgn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} | ({})", self.name(), self.acronym())
+    }
+}
+
+impl From<KnownPgn> for Pgn {
+    fn from(value: KnownPgn) -> Self {
+        value.pgn()
+    }
+}
+
+/// Builds a J1939 message for a catalogued parameter group, using its default priority.
+pub(crate) fn build_message(
+    pgn: KnownPgn,
+    source_address: SourceAddr,
+    destination_address: DestinationAddr,
+    bytes: [u8; 8],
+) -> Result<Message<J1939, Data>, Error> {
+    let id = IdJ1939Builder::new(pgn.pgn())
+        .priority(pgn.default_priority())
+        .source_address(source_address)
+        .destination_address(destination_address)
+        .build()?;
+
+    Ok(Message::from_parts(
+        id,
+        Pdu::<Data>::from_bits(u64::from_be_bytes(bytes)),
+    ))
+}
+
+/// Checks that a message carries the expected parameter group.
+///
+/// # Errors
+/// - [`Error::UnexpectedPgn`] if the PGN of the message is not `expected`.
+pub(crate) fn check_pgn(message: &Message<J1939, Data>, expected: KnownPgn) -> Result<(), Error> {
+    let pgn = message.id().pgn();
+
+    match pgn.lookup() {
+        Some(known) if known == expected => Ok(()),
+        _ => Err(Error::UnexpectedPgn(pgn.into_bits())),
+    }
 }
 
 #[cfg(test)]
 mod pgn_tests {
+    if_alloc! {
+        use alloc::format;
+    }
+
     // use crate::{
     //     conversion::Conversion,
     //     identifier::IdExtended,
@@ -320,7 +995,7 @@ mod pgn_tests {
     use crate::protocol::j1939::address::Addr;
 
     #[test]
-    fn test_pdu_assignment() -> Result<(), anyhow::Error> {
+    fn test_pdu_assignment() -> Result<(), Error> {
         let id_a = Id::<J1939>::try_from_hex("18FEF200")?;
         let id_b = Id::<J1939>::try_from_hex("1CFE9201")?;
         let id_c = Id::<J1939>::try_from_hex("10FF2121")?;
@@ -340,7 +1015,7 @@ mod pgn_tests {
     }
 
     #[test]
-    fn test_communication_mode() -> Result<(), anyhow::Error> {
+    fn test_communication_mode() -> Result<(), Error> {
         let id_a = Id::<J1939>::try_from_hex("18FEF200")?;
         let id_b = Id::<J1939>::try_from_hex("1CFE9201")?;
         let id_c = Id::<J1939>::try_from_hex("10FF2121")?;
@@ -360,7 +1035,7 @@ mod pgn_tests {
     }
 
     #[test]
-    fn test_destination_address() -> Result<(), anyhow::Error> {
+    fn test_destination_address() -> Result<(), Error> {
         let id_a = Id::<J1939>::try_from_hex("18FEF200")?;
         let id_b = Id::<J1939>::try_from_hex("1CFE9201")?;
         let id_c = Id::<J1939>::try_from_hex("10FF2121")?;
@@ -381,7 +1056,7 @@ mod pgn_tests {
     }
 
     #[test]
-    fn test_group_extension() -> Result<(), anyhow::Error> {
+    fn test_group_extension() -> Result<(), Error> {
         let id_a = Id::<J1939>::try_from_hex("18FEF200")?;
         let id_b = Id::<J1939>::try_from_hex("1CFE9201")?;
         let id_c = Id::<J1939>::try_from_hex("10FF2121")?;
@@ -401,7 +1076,7 @@ mod pgn_tests {
     }
 
     #[test]
-    fn test_pdu_format() -> Result<(), anyhow::Error> {
+    fn test_pdu_format() -> Result<(), Error> {
         let id_a = Id::<J1939>::try_from_hex("18FEF200")?;
         let id_b = Id::<J1939>::try_from_hex("1CFE9201")?;
         let id_c = Id::<J1939>::try_from_hex("10FF2121")?;
@@ -421,7 +1096,90 @@ mod pgn_tests {
     }
 
     #[test]
-    fn test_pgn_bits() -> Result<(), anyhow::Error> {
+    fn test_setters() -> Result<(), Error> {
+        let pgn_a = Pgn::try_from_bits(0xEA00)?;
+        let pgn_b = Pgn::try_from_bits(0xFEF2)?;
+
+        assert_eq!(
+            0xEA29,
+            pgn_a
+                .with_destination_address(DestinationAddr::Some(41))?
+                .into_bits()
+        );
+        assert_eq!(
+            0xEAFF,
+            pgn_a
+                .with_destination_address(DestinationAddr::None)?
+                .into_bits()
+        );
+        assert_eq!(
+            pgn_b,
+            pgn_b.with_destination_address(DestinationAddr::None)?
+        );
+        assert_eq!(
+            Err(Error::UnexpectedDestinationAddr(0xFEF2)),
+            pgn_b.with_destination_address(DestinationAddr::Some(41))
+        );
+        assert_eq!(
+            0x3_FEF2,
+            pgn_a
+                .with_reserved(true)
+                .with_data_page(true)
+                .with_pdu_format(0xFE)
+                .with_pdu_specific(0xF2)
+                .into_bits()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_id_builder() -> Result<(), Error> {
+        let pgn_a = Pgn::try_from_bits(61444)?;
+        let pgn_b = Pgn::try_from_bits(0xEA00)?;
+
+        let id_a = IdJ1939Builder::new(pgn_a)
+            .priority(3)
+            .source_address(SourceAddr::Some(0))
+            .build()?;
+        let id_b = IdJ1939Builder::new(pgn_b)
+            .source_address(SourceAddr::Some(249))
+            .destination_address(DestinationAddr::Some(0))
+            .build()?;
+        let id_c = IdJ1939Builder::new(pgn_b)
+            .source_address(SourceAddr::Some(249))
+            .build()?;
+
+        assert_eq!(Id::<J1939>::try_from_hex("0CF00400")?, id_a);
+        assert_eq!(pgn_a, id_a.pgn());
+        assert_eq!(Id::<J1939>::try_from_hex("18EA00F9")?, id_b);
+        assert_eq!(DestinationAddr::Some(0), id_b.pgn().destination_address());
+        assert_eq!(DestinationAddr::Some(255), id_c.pgn().destination_address());
+
+        assert_eq!(
+            Err(Error::UnexpectedDestinationAddr(61444)),
+            IdJ1939Builder::new(pgn_a)
+                .source_address(SourceAddr::Some(0))
+                .destination_address(DestinationAddr::Some(41))
+                .build()
+        );
+        assert_eq!(
+            Err(Error::MissingSourceAddr),
+            IdJ1939Builder::new(pgn_a).build()
+        );
+        assert_eq!(
+            Err(Error::InvalidPriority(8)),
+            IdJ1939Builder::new(pgn_a)
+                .priority(8)
+                .source_address(SourceAddr::Some(0))
+                .build()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pgn_bits() -> Result<(), Error> {
         let id_a = Id::<J1939>::try_from_hex("18FEF200")?;
         let id_b = Id::<J1939>::try_from_hex("1CFE9201")?;
         let id_c = Id::<J1939>::try_from_hex("10FF2121")?;
@@ -439,4 +1197,43 @@ mod pgn_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_known_pgn() -> Result<(), Error> {
+        let id_a = Id::<J1939>::try_from_hex("0CF00400")?;
+        let id_b = Id::<J1939>::try_from_hex("18EA00F9")?;
+        let id_c = Id::<J1939>::try_from_hex("1CFE9201")?;
+
+        assert_eq!(Some(KnownPgn::Eec1), id_a.pgn().lookup());
+        assert_eq!(Some(KnownPgn::Rqst), id_b.pgn().lookup());
+        assert_eq!(None, id_c.pgn().lookup());
+
+        assert_eq!("CCVS", KnownPgn::Ccvs.acronym());
+        assert_eq!("Cruise Control/Vehicle Speed", KnownPgn::Ccvs.name());
+        assert_eq!(6, KnownPgn::Ccvs.default_priority());
+        assert_eq!(Some(8), KnownPgn::Ccvs.data_length());
+        assert_eq!(
+            TransmissionRate::Periodic(100),
+            KnownPgn::Ccvs.transmission_rate()
+        );
+        assert_eq!(None, KnownPgn::Dm1.data_length());
+        assert_eq!(Pgn::from_bits(65265), Pgn::from(KnownPgn::Ccvs));
+
+        for known in KNOWN_PGNS {
+            assert_eq!(Some(known), known.pgn().lookup());
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_display() {
+        assert_eq!("65265 (CCVS)", format!("{}", Pgn::from_bits(65265)));
+        assert_eq!("65170", format!("{}", Pgn::from_bits(65170)));
+        assert_eq!(
+            "Electronic Engine Controller 1 | (EEC1)",
+            format!("{}", KnownPgn::Eec1)
+        );
+    }
 }
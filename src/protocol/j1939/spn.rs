@@ -0,0 +1,260 @@
+// Copyright (c) 2024 Nathan H. Keough
+//
+// This work is dual-licensed under MIT OR Apache 2.0 (or any later version).
+// You may choose between one of them if you use this work.
+//
+// For further detail, please refer to the individual licenses located at the root of this crate.
+
+//! # J1939 Suspect Parameter Number (SPN)
+//!
+//! **Description:**
+//! A Suspect Parameter Number (SPN) identifies a single parameter, such as engine speed or coolant
+//! temperature, carried within a parameter group. SAE J1939-71 assigns each SPN a position in the
+//! data field of its PGN, a length, and the resolution, offset and unit of its physical value.
+//!
+//! This module provides [`SPNS`], a static table of commonly broadcast SPNs, and [`decode_spns`],
+//! which decodes every catalogued SPN carried by a message.
+//!
+//! **Source Documents:**
+//! - *SAE J1939-71*
+
+use crate::{
+    error::Error,
+    message::Message,
+    payload::Data,
+    protocol::j1939::{identifier::J1939, pgn::KnownPgn},
+    signal::{ByteOrder, PhysicalValue, SignalSpec},
+};
+
+/// Represents a Suspect Parameter Number (SPN) and the layout of its signal within its parameter
+/// group.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spn {
+    number: u32,
+    name: &'static str,
+    pgn: KnownPgn,
+    spec: SignalSpec,
+}
+
+impl Spn {
+    /// Creates a new SPN definition for an unsigned, little endian J1939 parameter with "error"
+    /// and "not available" sentinels enabled.
+    ///
+    /// # Arguments
+    /// - `number`: The suspect parameter number.
+    /// - `name`: The name of the parameter.
+    /// - `pgn`: The [`KnownPgn`] carrying the parameter.
+    /// - `start_bit`: The start bit of the parameter, where byte 1 bit 1 in J1939-71 notation is 0.
+    /// - `length`: The length of the parameter in bits.
+    /// - `resolution`: The physical value of one raw bit.
+    /// - `offset`: The physical value of a raw value of zero.
+    /// - `range`: The inclusive range of valid physical values.
+    /// - `unit`: The unit of the physical value.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub const fn new(
+        number: u32,
+        name: &'static str,
+        pgn: KnownPgn,
+        start_bit: u8,
+        length: u8,
+        resolution: f64,
+        offset: f64,
+        range: (f64, f64),
+        unit: &'static str,
+    ) -> Self {
+        Self {
+            number,
+            name,
+            pgn,
+            spec: SignalSpec::new(start_bit, length, ByteOrder::LittleEndian, false)
+                .with_scale(resolution, offset)
+                .with_range(range.0, range.1)
+                .with_unit(unit)
+                .with_sentinels(true),
+        }
+    }
+
+    /// Looks up a catalogued SPN by its number.
+    ///
+    /// # Returns
+    /// - `Some(Spn)`: If the SPN is present in [`SPNS`].
+    /// - `None`: If the SPN is unknown.
+    #[must_use]
+    pub fn lookup(number: u32) -> Option<Spn> {
+        SPNS.iter().copied().find(|spn| spn.number == number)
+    }
+
+    /// Returns the suspect parameter number.
+    #[inline]
+    #[must_use]
+    pub const fn number(&self) -> u32 {
+        self.number
+    }
+
+    /// Returns the name of the parameter.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the parameter group carrying the parameter.
+    #[inline]
+    #[must_use]
+    pub const fn pgn(&self) -> KnownPgn {
+        self.pgn
+    }
+
+    /// Returns the [`SignalSpec`] describing the position, length, scaling, range and unit of the
+    /// parameter.
+    #[inline]
+    #[must_use]
+    pub const fn spec(&self) -> &SignalSpec {
+        &self.spec
+    }
+
+    /// Returns the unit of the physical value.
+    #[inline]
+    #[must_use]
+    pub const fn unit(&self) -> &'static str {
+        self.spec.unit()
+    }
+}
+
+/// Static table of commonly broadcast SAE J1939-71 SPNs, grouped by parameter group.
+#[rustfmt::skip]
+pub static SPNS: [Spn; 29] = [
+    Spn::new(512, "Driver's Demand Engine - Percent Torque", KnownPgn::Eec1, 8, 8, 1.0, -125.0, (-125.0, 125.0), "%"),
+    Spn::new(513, "Actual Engine - Percent Torque", KnownPgn::Eec1, 16, 8, 1.0, -125.0, (-125.0, 125.0), "%"),
+    Spn::new(190, "Engine Speed", KnownPgn::Eec1, 24, 16, 0.125, 0.0, (0.0, 8_031.875), "rpm"),
+    Spn::new(91, "Accelerator Pedal Position 1", KnownPgn::Eec2, 8, 8, 0.4, 0.0, (0.0, 100.0), "%"),
+    Spn::new(92, "Engine Percent Load At Current Speed", KnownPgn::Eec2, 16, 8, 1.0, 0.0, (0.0, 250.0), "%"),
+    Spn::new(191, "Transmission Output Shaft Speed", KnownPgn::Etc1, 8, 16, 0.125, 0.0, (0.0, 8_031.875), "rpm"),
+    Spn::new(904, "Front Axle Speed", KnownPgn::Ebc2, 0, 16, 1.0 / 256.0, 0.0, (0.0, 250.996_093_75), "km/h"),
+    Spn::new(245, "Total Vehicle Distance", KnownPgn::Vd, 32, 32, 0.125, 0.0, (0.0, 526_385_151.875), "km"),
+    Spn::new(247, "Engine Total Hours of Operation", KnownPgn::Hours, 0, 32, 0.05, 0.0, (0.0, 210_554_060.75), "h"),
+    Spn::new(250, "Engine Total Fuel Used", KnownPgn::Lfc, 32, 32, 0.5, 0.0, (0.0, 2_105_540_607.5), "L"),
+    Spn::new(110, "Engine Coolant Temperature", KnownPgn::Et1, 0, 8, 1.0, -40.0, (-40.0, 210.0), "degC"),
+    Spn::new(174, "Engine Fuel Temperature 1", KnownPgn::Et1, 8, 8, 1.0, -40.0, (-40.0, 210.0), "degC"),
+    Spn::new(175, "Engine Oil Temperature 1", KnownPgn::Et1, 16, 16, 0.031_25, -273.0, (-273.0, 1_734.968_75), "degC"),
+    Spn::new(94, "Engine Fuel Delivery Pressure", KnownPgn::Eflp1, 0, 8, 4.0, 0.0, (0.0, 1000.0), "kPa"),
+    Spn::new(98, "Engine Oil Level", KnownPgn::Eflp1, 16, 8, 0.4, 0.0, (0.0, 100.0), "%"),
+    Spn::new(100, "Engine Oil Pressure", KnownPgn::Eflp1, 24, 8, 4.0, 0.0, (0.0, 1000.0), "kPa"),
+    Spn::new(111, "Engine Coolant Level", KnownPgn::Eflp1, 56, 8, 0.4, 0.0, (0.0, 100.0), "%"),
+    Spn::new(84, "Wheel-Based Vehicle Speed", KnownPgn::Ccvs, 8, 16, 1.0 / 256.0, 0.0, (0.0, 250.996_093_75), "km/h"),
+    Spn::new(183, "Engine Fuel Rate", KnownPgn::Lfe, 0, 16, 0.05, 0.0, (0.0, 3_212.75), "L/h"),
+    Spn::new(184, "Engine Instantaneous Fuel Economy", KnownPgn::Lfe, 16, 16, 1.0 / 512.0, 0.0, (0.0, 125.5), "km/L"),
+    Spn::new(185, "Engine Average Fuel Economy", KnownPgn::Lfe, 32, 16, 1.0 / 512.0, 0.0, (0.0, 125.5), "km/L"),
+    Spn::new(51, "Engine Throttle Valve 1 Position", KnownPgn::Lfe, 48, 8, 0.4, 0.0, (0.0, 100.0), "%"),
+    Spn::new(108, "Barometric Pressure", KnownPgn::Amb, 0, 8, 0.5, 0.0, (0.0, 125.0), "kPa"),
+    Spn::new(171, "Ambient Air Temperature", KnownPgn::Amb, 24, 16, 0.031_25, -273.0, (-273.0, 1_734.968_75), "degC"),
+    Spn::new(172, "Engine Air Inlet Temperature", KnownPgn::Amb, 40, 8, 1.0, -40.0, (-40.0, 210.0), "degC"),
+    Spn::new(102, "Engine Intake Manifold #1 Pressure", KnownPgn::Ic1, 8, 8, 2.0, 0.0, (0.0, 500.0), "kPa"),
+    Spn::new(105, "Engine Intake Manifold 1 Temperature", KnownPgn::Ic1, 16, 8, 1.0, -40.0, (-40.0, 210.0), "degC"),
+    Spn::new(168, "Battery Potential / Power Input 1", KnownPgn::Vep1, 32, 16, 0.05, 0.0, (0.0, 3_212.75), "V"),
+    Spn::new(96, "Fuel Level 1", KnownPgn::Dd, 8, 8, 0.4, 0.0, (0.0, 100.0), "%"),
+];
+
+/// Decodes every catalogued SPN carried by a J1939 message.
+///
+/// "Error" and "not available" sentinels are yielded as [`PhysicalValue::Error`] and
+/// [`PhysicalValue::NotAvailable`], while parameters that cannot be decoded are yielded with the
+/// error from [`SignalSpec::decode`], so a missing parameter is never confused with an invalid one.
+///
+/// # Arguments
+/// - `message`: The J1939 message to decode.
+///
+/// # Returns
+/// An iterator of `(Spn, Result<PhysicalValue, Error>)` pairs, empty if the PGN of the message is
+/// not catalogued. The result is [`Error::PhysicalOutOfRange`] if the value lies outside the valid
+/// range of the SPN.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// // EEC1 from the primary engine controller: 860 rpm, 5% actual torque.
+/// let msg_a = Message::<J1939, Data>::from_hex("0CF00400", "F07D82E01AFFFFFF");
+///
+/// let mut spns = decode_spns(&msg_a);
+/// let (spn, value) = spns.nth(2).unwrap();
+///
+/// assert_eq!(190, spn.number());
+/// assert_eq!(Some(860.0), value.unwrap().as_f64());
+/// assert_eq!("rpm", spn.unit());
+/// ```
+pub fn decode_spns(
+    message: &Message<J1939, Data>,
+) -> impl Iterator<Item = (Spn, Result<PhysicalValue, Error>)> {
+    let known = message.id().pgn().lookup();
+    let pdu = message.pdu();
+
+    SPNS.iter()
+        .filter(move |spn| Some(spn.pgn) == known)
+        .map(move |spn| (*spn, spn.spec.decode(&pdu)))
+}
+
+#[cfg(test)]
+mod spn_tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let spn_a = Spn::lookup(110).unwrap();
+
+        assert_eq!("Engine Coolant Temperature", spn_a.name());
+        assert_eq!(KnownPgn::Et1, spn_a.pgn());
+        assert_eq!("degC", spn_a.unit());
+        assert_eq!(None, Spn::lookup(0));
+
+        for spn in &SPNS {
+            assert_eq!(Some(*spn), Spn::lookup(spn.number()));
+            assert!(spn.pgn().data_length().is_some());
+        }
+    }
+
+    #[test]
+    fn test_decode_spns() {
+        // CCVS: 50 km/h wheel-based vehicle speed.
+        let msg_a = Message::<J1939, Data>::from_hex("18FEF100", "FF0032FFFFFFFFFF");
+        // ET1: 90 degC coolant, fuel temperature not available, oil temperature error.
+        let msg_b = Message::<J1939, Data>::from_hex("18FEEE00", "82FFFFFEFFFFFFFF");
+        // Unknown PGN.
+        let msg_c = Message::<J1939, Data>::from_hex("1CFE9201", "0000000000000000");
+        // ET1: 211 degC coolant is outside the valid range of SPN 110.
+        let msg_d = Message::<J1939, Data>::from_hex("18FEEE00", "FBFFFFFEFFFFFFFF");
+
+        let mut spns_a = decode_spns(&msg_a);
+        let (spn_a, value_a) = spns_a.next().unwrap();
+
+        assert_eq!(84, spn_a.number());
+        assert_eq!(Ok(PhysicalValue::Value(50.0)), value_a);
+        assert!(spns_a.next().is_none());
+
+        let mut spns_b = decode_spns(&msg_b);
+
+        assert_eq!(
+            Some((Spn::lookup(110).unwrap(), Ok(PhysicalValue::Value(90.0)))),
+            spns_b.next()
+        );
+        assert_eq!(
+            Some((Spn::lookup(174).unwrap(), Ok(PhysicalValue::NotAvailable))),
+            spns_b.next()
+        );
+        assert_eq!(
+            Some((Spn::lookup(175).unwrap(), Ok(PhysicalValue::Error))),
+            spns_b.next()
+        );
+        assert_eq!(None, spns_b.next());
+
+        let mut spns_d = decode_spns(&msg_d);
+
+        assert_eq!(
+            Some((Spn::lookup(110).unwrap(), Err(Error::PhysicalOutOfRange))),
+            spns_d.next()
+        );
+        assert_eq!(3, decode_spns(&msg_d).count());
+
+        assert_eq!(0, decode_spns(&msg_c).count());
+    }
+}
@@ -0,0 +1,1844 @@
+// Copyright (c) 2024 Nathan H. Keough
+//
+// This work is dual-licensed under MIT OR Apache 2.0 (or any later version).
+// You may choose between one of them if you use this work.
+//
+// For further detail, please refer to the individual licenses located at the root of this crate.
+
+//! # J1939 Transport Protocol (TP)
+//!
+//! **Description:**
+//! Parameter groups longer than 8 bytes, such as the VIN or a DM1 with several DTCs, are sent in
+//! up to 255 packets of 7 bytes each, for a payload of at most 1785 bytes. Two parameter groups
+//! carry a transfer:
+//!
+//! - *TP.CM (PGN 60416):* Connection management. Announces a transfer with a Broadcast Announce
+//!   Message (BAM) or a Request To Send (RTS), paces it with Clear To Send (CTS), acknowledges it
+//!   with End Of Message Acknowledgment (EndOfMsgAck), or aborts it.
+//! - *TP.DT (PGN 60160):* Data transfer. Byte 1 holds the sequence number, starting at 1, and
+//!   bytes 2-8 hold the next 7 bytes of the payload.
+//!
+//! A BAM is sent to the global address and its packets follow every 50 to 200 ms without
+//! handshaking. [`BamReceiver`] reassembles them using timestamps supplied by the caller, so
+//! it does not depend on a clock or an allocator.
+//!
+//! A transfer to a specific destination uses the connection mode instead: the originator sends
+//! an RTS, the responder paces the transfer with CTS messages and acknowledges it with an
+//! EndOfMsgAck. [`RtsCtsOriginator`] and [`RtsCtsResponder`] implement both sides as sans-IO
+//! state machines: they consume received messages and timestamps, and return the frames to send.
+//!
+//! [`segment`] splits a payload into the announce frame and the TP.DT packets of either mode.
+//!
+//! **Source Documents:**
+//! - *SAE J1939-21*
+
+use core::time::Duration;
+
+pub use crate::error::AbortReason;
+
+use crate::{
+    conversion::Conversion,
+    error::Error,
+    message::Message,
+    payload::{Data, Pdu},
+};
+
+use super::{
+    address::{DestinationAddr, SourceAddr},
+    identifier::J1939,
+    pgn::{build_message, check_pgn, KnownPgn, Pgn},
+};
+
+/// The largest payload, in bytes, that can be sent with the transport protocol.
+pub const MAX_TP_SIZE: usize = 1785;
+
+/// The number of payload bytes carried by each TP.DT packet.
+pub const TP_DT_LEN: usize = 7;
+
+/// The global destination address, used by BAM transfers.
+const GLOBAL_ADDRESS: u8 = 255;
+
+/// Represents a TP.CM (Transport Protocol - Connection Management, PGN 60416) message.
+///
+/// Every variant carries the PGN of the transferred parameter group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TpCm {
+    /// Request To Send, opening a connection-mode transfer.
+    Rts {
+        /// The payload size in bytes.
+        size: u16,
+        /// The number of TP.DT packets.
+        packets: u8,
+        /// The largest number of packets the originator sends per CTS, or 255 for no limit.
+        max_packets_per_cts: u8,
+        /// The PGN of the transferred parameter group.
+        pgn: Pgn,
+    },
+    /// Clear To Send, allowing the originator to send a window of packets.
+    Cts {
+        /// The number of packets that can be sent; zero holds the connection open.
+        packets: u8,
+        /// The sequence number of the next packet to send.
+        next_packet: u8,
+        /// The PGN of the transferred parameter group.
+        pgn: Pgn,
+    },
+    /// End Of Message Acknowledgment, closing a connection-mode transfer.
+    EndOfMsgAck {
+        /// The payload size in bytes.
+        size: u16,
+        /// The number of TP.DT packets.
+        packets: u8,
+        /// The PGN of the transferred parameter group.
+        pgn: Pgn,
+    },
+    /// Broadcast Announce Message, opening a broadcast transfer.
+    Bam {
+        /// The payload size in bytes.
+        size: u16,
+        /// The number of TP.DT packets.
+        packets: u8,
+        /// The PGN of the transferred parameter group.
+        pgn: Pgn,
+    },
+    /// Connection Abort, closing a connection-mode transfer early.
+    Abort {
+        /// The reason for the abort.
+        reason: AbortReason,
+        /// The PGN of the transferred parameter group.
+        pgn: Pgn,
+    },
+}
+
+impl TpCm {
+    /// Returns the PGN of the transferred parameter group.
+    #[must_use]
+    pub const fn pgn(&self) -> Pgn {
+        match *self {
+            TpCm::Rts { pgn, .. }
+            | TpCm::Cts { pgn, .. }
+            | TpCm::EndOfMsgAck { pgn, .. }
+            | TpCm::Bam { pgn, .. }
+            | TpCm::Abort { pgn, .. } => pgn,
+        }
+    }
+
+    /// Decodes a TP.CM message from its eight data bytes.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidControlByte`] if the control byte is not a known TP.CM control byte.
+    /// - [`Error::PgnBitsOutOfRange`] if the PGN exceeds 18 bits.
+    pub fn from_bytes(bytes: [u8; 8]) -> Result<Self, Error> {
+        let pgn = Pgn::try_from_bits(u32::from_le_bytes([bytes[5], bytes[6], bytes[7], 0]))?;
+        let size = u16::from_le_bytes([bytes[1], bytes[2]]);
+
+        match bytes[0] {
+            16 => Ok(TpCm::Rts {
+                size,
+                packets: bytes[3],
+                max_packets_per_cts: bytes[4],
+                pgn,
+            }),
+            17 => Ok(TpCm::Cts {
+                packets: bytes[1],
+                next_packet: bytes[2],
+                pgn,
+            }),
+            19 => Ok(TpCm::EndOfMsgAck {
+                size,
+                packets: bytes[3],
+                pgn,
+            }),
+            32 => Ok(TpCm::Bam {
+                size,
+                packets: bytes[3],
+                pgn,
+            }),
+            255 => Ok(TpCm::Abort {
+                reason: bytes[1].into(),
+                pgn,
+            }),
+            c => Err(Error::InvalidControlByte(c)),
+        }
+    }
+
+    /// Encodes the TP.CM message into its eight data bytes.
+    #[must_use]
+    pub fn to_bytes(self) -> [u8; 8] {
+        let [pgn_0, pgn_1, pgn_2, _] = self.pgn().into_bits().to_le_bytes();
+
+        let head = match self {
+            TpCm::Rts {
+                size,
+                packets,
+                max_packets_per_cts,
+                ..
+            } => {
+                let [size_0, size_1] = size.to_le_bytes();
+                [16, size_0, size_1, packets, max_packets_per_cts]
+            }
+            TpCm::Cts {
+                packets,
+                next_packet,
+                ..
+            } => [17, packets, next_packet, 0xFF, 0xFF],
+            TpCm::EndOfMsgAck { size, packets, .. } => {
+                let [size_0, size_1] = size.to_le_bytes();
+                [19, size_0, size_1, packets, 0xFF]
+            }
+            TpCm::Bam { size, packets, .. } => {
+                let [size_0, size_1] = size.to_le_bytes();
+                [32, size_0, size_1, packets, 0xFF]
+            }
+            TpCm::Abort { reason, .. } => [255, reason.into(), 0xFF, 0xFF, 0xFF],
+        };
+
+        [
+            head[0], head[1], head[2], head[3], head[4], pgn_0, pgn_1, pgn_2,
+        ]
+    }
+
+    /// Encodes the TP.CM message into a message.
+    ///
+    /// A [`TpCm::Bam`] is always sent to the global address, whatever `destination_address` is.
+    ///
+    /// # Errors
+    /// - [`Error::MissingSourceAddr`] if `source_address` is [`SourceAddr::None`].
+    pub fn to_message(
+        self,
+        source_address: SourceAddr,
+        destination_address: DestinationAddr,
+    ) -> Result<Message<J1939, Data>, Error> {
+        let destination_address = match self {
+            TpCm::Bam { .. } => DestinationAddr::None,
+            _ => destination_address,
+        };
+
+        build_message(
+            KnownPgn::TpCm,
+            source_address,
+            destination_address,
+            self.to_bytes(),
+        )
+    }
+}
+
+impl TryFrom<&Message<J1939, Data>> for TpCm {
+    type Error = Error;
+
+    fn try_from(value: &Message<J1939, Data>) -> Result<Self, Self::Error> {
+        check_pgn(value, KnownPgn::TpCm)?;
+
+        TpCm::from_bytes(value.pdu().to_be_bytes())
+    }
+}
+
+/// Represents a TP.DT (Transport Protocol - Data Transfer, PGN 60160) packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TpDt {
+    sequence: u8,
+    data: [u8; TP_DT_LEN],
+}
+
+impl TpDt {
+    /// Creates a new packet from its sequence number and 7 payload bytes.
+    #[inline]
+    #[must_use]
+    pub const fn new(sequence: u8, data: [u8; TP_DT_LEN]) -> Self {
+        Self { sequence, data }
+    }
+
+    /// Returns the sequence number of the packet, starting at 1.
+    #[inline]
+    #[must_use]
+    pub const fn sequence(&self) -> u8 {
+        self.sequence
+    }
+
+    /// Returns the 7 payload bytes of the packet.
+    #[inline]
+    #[must_use]
+    pub const fn data(&self) -> &[u8; TP_DT_LEN] {
+        &self.data
+    }
+
+    /// Encodes the packet into a message.
+    ///
+    /// # Errors
+    /// - [`Error::MissingSourceAddr`] if `source_address` is [`SourceAddr::None`].
+    pub fn to_message(
+        self,
+        source_address: SourceAddr,
+        destination_address: DestinationAddr,
+    ) -> Result<Message<J1939, Data>, Error> {
+        let mut bytes = [self.sequence; 8];
+        bytes[1..].copy_from_slice(&self.data);
+
+        build_message(KnownPgn::TpDt, source_address, destination_address, bytes)
+    }
+}
+
+impl TpDt {
+    /// Creates the packet with the given sequence number from a payload, padding the last
+    /// packet with `0xFF`.
+    ///
+    /// # Arguments
+    /// - `payload`: The whole payload of the transfer.
+    /// - `sequence`: The sequence number of the packet, starting at 1.
+    #[must_use]
+    pub fn from_payload(payload: &[u8], sequence: u8) -> Self {
+        let offset = usize::from(sequence.saturating_sub(1)) * TP_DT_LEN;
+        let chunk = payload.get(offset..).unwrap_or_default();
+        let len = chunk.len().min(TP_DT_LEN);
+        let mut data = [0xFF; TP_DT_LEN];
+        data[..len].copy_from_slice(&chunk[..len]);
+
+        Self { sequence, data }
+    }
+}
+
+impl From<Pdu<Data>> for TpDt {
+    fn from(value: Pdu<Data>) -> Self {
+        let bytes = value.to_be_bytes();
+        let mut data = [0; TP_DT_LEN];
+        data.copy_from_slice(&bytes[1..]);
+
+        Self {
+            sequence: bytes[0],
+            data,
+        }
+    }
+}
+
+impl TryFrom<&Message<J1939, Data>> for TpDt {
+    type Error = Error;
+
+    fn try_from(value: &Message<J1939, Data>) -> Result<Self, Self::Error> {
+        check_pgn(value, KnownPgn::TpDt)?;
+
+        Ok(value.pdu().into())
+    }
+}
+
+/// Returns the number of TP.DT packets needed to carry `size` bytes.
+#[must_use]
+pub const fn packet_count(size: usize) -> usize {
+    size.div_ceil(TP_DT_LEN)
+}
+
+/// Validates the size of a transfer.
+///
+/// # Errors
+/// - [`Error::InvalidDataLength`] if `size` is not between 9 and `max` bytes inclusive.
+fn check_size(size: usize, max: usize) -> Result<u8, Error> {
+    match u8::try_from(packet_count(size)) {
+        Ok(packets) if size > 8 && size <= max => Ok(packets),
+        _ => Err(Error::InvalidDataLength(size)),
+    }
+}
+
+/// Iterator over the frames carrying a payload with the transport protocol, created by
+/// [`segment`].
+///
+/// It yields the TP.CM announce frame, then the TP.DT packets in sequence.
+#[derive(Debug, Clone)]
+pub struct Segments<'a> {
+    announce: Option<TpCm>,
+    source_address: SourceAddr,
+    destination_address: DestinationAddr,
+    payload: &'a [u8],
+    next_packet: u16,
+    packets: u16,
+}
+
+impl Iterator for Segments<'_> {
+    type Item = Message<J1939, Data>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(cm) = self.announce.take() {
+            return cm
+                .to_message(self.source_address, self.destination_address)
+                .ok();
+        }
+
+        if self.next_packet > self.packets {
+            return None;
+        }
+
+        let dt = TpDt::from_payload(self.payload, u8::try_from(self.next_packet).ok()?);
+        self.next_packet += 1;
+
+        dt.to_message(self.source_address, self.destination_address)
+            .ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len =
+            usize::from(self.announce.is_some()) + usize::from(self.packets + 1 - self.next_packet);
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Segments<'_> {}
+
+/// Splits a payload into the frames that carry it with the transport protocol.
+///
+/// A payload sent to the global address, or to [`DestinationAddr::None`], is announced with a
+/// BAM; otherwise it is announced with an RTS, and the returned packets should be sent as
+/// allowed by the CTS messages of the responder (see [`RtsCtsOriginator`] to handle the
+/// handshake). The last packet is padded with `0xFF`.
+///
+/// # Arguments
+/// - `pgn`: The PGN of the transferred parameter group.
+/// - `source_address`: The address of the sender.
+/// - `destination_address`: The address of the receiver.
+/// - `payload`: The payload to send, between 9 and 1785 bytes.
+///
+/// # Errors
+/// - [`Error::InvalidDataLength`] if the payload is not between 9 and 1785 bytes.
+/// - [`Error::MissingSourceAddr`] if `source_address` is [`SourceAddr::None`].
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// use can_types::protocol::j1939::transport::segment;
+///
+/// # fn main() -> Result<(), can_types::error::Error> {
+/// let vin = b"1HGBH41JXMN109186*";
+/// let pgn = Pgn::from_bits(65260);
+/// let mut frames = segment(pgn, SourceAddr::Some(0), DestinationAddr::None, vin)?;
+///
+/// assert_eq!(4, frames.len());
+///
+/// let bam = frames.next().unwrap();
+/// assert_eq!(IdJ1939::from_hex("1CECFF00"), bam.id());
+/// assert_eq!(Pdu::<Data>::from_hex("20120003FFECFE00"), bam.pdu());
+///
+/// let last = frames.last().unwrap();
+/// assert_eq!(IdJ1939::from_hex("1CEBFF00"), last.id());
+/// assert_eq!(Pdu::<Data>::from_hex("033138362AFFFFFF"), last.pdu());
+/// # Ok(())
+/// # }
+/// ```
+pub fn segment(
+    pgn: Pgn,
+    source_address: SourceAddr,
+    destination_address: DestinationAddr,
+    payload: &[u8],
+) -> Result<Segments<'_>, Error> {
+    let packets = check_size(payload.len(), MAX_TP_SIZE)?;
+
+    if source_address == SourceAddr::None {
+        return Err(Error::MissingSourceAddr);
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let size = payload.len() as u16;
+    let (announce, destination_address) = match destination_address {
+        DestinationAddr::None | DestinationAddr::Some(GLOBAL_ADDRESS) => {
+            (TpCm::Bam { size, packets, pgn }, DestinationAddr::None)
+        }
+        DestinationAddr::Some(_) => (
+            TpCm::Rts {
+                size,
+                packets,
+                max_packets_per_cts: 0xFF,
+                pgn,
+            },
+            destination_address,
+        ),
+    };
+
+    Ok(Segments {
+        announce: Some(announce),
+        source_address,
+        destination_address,
+        payload,
+        next_packet: 1,
+        packets: packets.into(),
+    })
+}
+
+/// A reassembled payload: the PGN of the parameter group, the address of the sender and the
+/// payload bytes.
+pub type Reassembled<'a> = (Pgn, SourceAddr, &'a [u8]);
+
+/// The state of a BAM transfer in progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BamSession {
+    pgn: Pgn,
+    source_address: SourceAddr,
+    size: usize,
+    packets: u8,
+    next_packet: u8,
+    last_packet_at: Duration,
+}
+
+/// Reassembles payloads sent with the Broadcast Announce Message (BAM) transport protocol.
+///
+/// The receiver tracks one transfer at a time, storing the payload in a fixed-capacity buffer of
+/// `N` bytes, so it works without an allocator. Use one receiver per source address to follow
+/// concurrent transfers. Timestamps are supplied by the caller and only need to be monotonic.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// # use core::time::Duration;
+/// # fn main() -> Result<(), can_types::error::Error> {
+/// let mut receiver: BamReceiver = BamReceiver::new();
+///
+/// // The primary engine controller broadcasts a 10-byte payload of PGN 65260 (VI).
+/// let bam = Message::<J1939, Data>::from_hex("1CECFF00", "200A0002FFECFE00");
+/// let dt_1 = Message::<J1939, Data>::from_hex("1CEBFF00", "0131484750313233");
+/// let dt_2 = Message::<J1939, Data>::from_hex("1CEBFF00", "02343536FFFFFFFF");
+///
+/// assert_eq!(None, receiver.receive(&bam, Duration::from_millis(0))?);
+/// assert_eq!(None, receiver.receive(&dt_1, Duration::from_millis(50))?);
+///
+/// let (pgn, source_address, payload) = receiver
+///     .receive(&dt_2, Duration::from_millis(100))?
+///     .unwrap();
+///
+/// assert_eq!(Pgn::from_bits(65260), pgn);
+/// assert_eq!(SourceAddr::Some(0), source_address);
+/// assert_eq!(b"1HGP123456", payload);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BamReceiver<const N: usize = MAX_TP_SIZE> {
+    session: Option<BamSession>,
+    buffer: [u8; N],
+    min_gap: Duration,
+    max_gap: Duration,
+}
+
+impl<const N: usize> Default for BamReceiver<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> BamReceiver<N> {
+    /// Creates a new receiver enforcing the 50 to 200 ms inter-packet timing of SAE J1939-21.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            session: None,
+            buffer: [0; N],
+            min_gap: Duration::from_millis(50),
+            max_gap: Duration::from_millis(200),
+        }
+    }
+
+    /// Returns a copy of the receiver enforcing the given inclusive range of time between the
+    /// BAM and the first packet, and between consecutive packets.
+    #[must_use]
+    pub const fn with_timing(mut self, min_gap: Duration, max_gap: Duration) -> Self {
+        self.min_gap = min_gap;
+        self.max_gap = max_gap;
+        self
+    }
+
+    /// Returns `true` if a transfer is in progress.
+    #[inline]
+    #[must_use]
+    pub const fn is_active(&self) -> bool {
+        self.session.is_some()
+    }
+
+    /// Discards the transfer in progress, if any.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.session = None;
+    }
+
+    /// Consumes a received message.
+    ///
+    /// Messages other than a BAM, a TP.CM abort or a TP.DT sent to the global address are
+    /// ignored, as are packets from another source than the transfer in progress. A BAM
+    /// received while a transfer is in progress replaces it if it comes from the same source or
+    /// if the transfer timed out.
+    ///
+    /// # Arguments
+    /// - `message`: The received message.
+    /// - `now`: The time at which the message was received.
+    ///
+    /// # Returns
+    /// - `Some((pgn, source_address, payload))`: If the message completed a transfer.
+    /// - `None`: If the message was consumed or ignored.
+    ///
+    /// # Errors
+    /// The transfer in progress is discarded and:
+    /// - [`Error::InvalidDataLength`] if a BAM announces an empty payload, a payload larger
+    ///   than the buffer, or a number of packets that does not match its size.
+    /// - [`Error::TransportAborted`] if the sender aborts, or if a packet has a bad or
+    ///   duplicate sequence number.
+    /// - [`Error::TransportTiming`] if a packet arrives too early or too late.
+    pub fn receive(
+        &mut self,
+        message: &Message<J1939, Data>,
+        now: Duration,
+    ) -> Result<Option<Reassembled<'_>>, Error> {
+        let id = message.id();
+        let source_address = id.source_address();
+
+        if id.pgn().destination_address() != DestinationAddr::Some(GLOBAL_ADDRESS) {
+            return Ok(None);
+        }
+
+        match id.pgn().lookup() {
+            Some(KnownPgn::TpCm) => self.receive_cm(TpCm::try_from(message)?, source_address, now),
+            Some(KnownPgn::TpDt) => self.receive_dt(TpDt::try_from(message)?, source_address, now),
+            _ => Ok(None),
+        }
+    }
+
+    /// Checks the transfer in progress for a timeout, for use when no message is received.
+    ///
+    /// # Errors
+    /// - [`Error::TransportTiming`] if the next packet is overdue. The transfer is discarded.
+    pub fn poll(&mut self, now: Duration) -> Result<(), Error> {
+        match self.session {
+            Some(session) if now.saturating_sub(session.last_packet_at) > self.max_gap => {
+                self.session = None;
+                Err(Error::TransportTiming(
+                    now.saturating_sub(session.last_packet_at),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Consumes a TP.CM message sent to the global address.
+    fn receive_cm(
+        &mut self,
+        cm: TpCm,
+        source_address: SourceAddr,
+        now: Duration,
+    ) -> Result<Option<Reassembled<'_>>, Error> {
+        let in_session = matches!(self.session, Some(s) if s.source_address == source_address);
+
+        match cm {
+            TpCm::Bam { size, packets, pgn } => {
+                if self.session.is_some() && !in_session && self.poll(now).is_ok() {
+                    return Ok(None);
+                }
+
+                let size = usize::from(size);
+                self.session = None;
+
+                if size == 0 || size > N || packet_count(size) != usize::from(packets) {
+                    return Err(Error::InvalidDataLength(size));
+                }
+
+                self.session = Some(BamSession {
+                    pgn,
+                    source_address,
+                    size,
+                    packets,
+                    next_packet: 1,
+                    last_packet_at: now,
+                });
+
+                Ok(None)
+            }
+            TpCm::Abort { reason, .. } if in_session => {
+                self.session = None;
+                Err(Error::TransportAborted(reason))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Consumes a TP.DT packet sent to the global address.
+    fn receive_dt(
+        &mut self,
+        dt: TpDt,
+        source_address: SourceAddr,
+        now: Duration,
+    ) -> Result<Option<Reassembled<'_>>, Error> {
+        let Some(mut session) = self.session else {
+            return Ok(None);
+        };
+
+        if session.source_address != source_address {
+            return Ok(None);
+        }
+
+        self.session = None;
+
+        let elapsed = now.saturating_sub(session.last_packet_at);
+
+        if elapsed < self.min_gap || elapsed > self.max_gap {
+            return Err(Error::TransportTiming(elapsed));
+        }
+
+        if dt.sequence != session.next_packet {
+            return Err(Error::TransportAborted(
+                if dt.sequence != 0 && dt.sequence == session.next_packet - 1 {
+                    AbortReason::DuplicateSequenceNumber
+                } else {
+                    AbortReason::BadSequenceNumber
+                },
+            ));
+        }
+
+        let offset = usize::from(dt.sequence - 1) * TP_DT_LEN;
+        let len = TP_DT_LEN.min(session.size - offset);
+        self.buffer[offset..offset + len].copy_from_slice(&dt.data[..len]);
+
+        if dt.sequence == session.packets {
+            return Ok(Some((
+                session.pgn,
+                source_address,
+                &self.buffer[..session.size],
+            )));
+        }
+
+        session.next_packet += 1;
+        session.last_packet_at = now;
+        self.session = Some(session);
+
+        Ok(None)
+    }
+}
+
+/// Time a responder waits for the next TP.DT packet of a window (T1).
+pub const T1: Duration = Duration::from_millis(750);
+
+/// Time a responder waits for the first TP.DT packet after sending a CTS (T2).
+pub const T2: Duration = Duration::from_millis(1250);
+
+/// Time an originator waits for a CTS or an EndOfMsgAck after sending a packet (T3).
+pub const T3: Duration = Duration::from_millis(1250);
+
+/// Time an originator waits for the next CTS after a hold-off CTS (T4).
+pub const T4: Duration = Duration::from_millis(1050);
+
+/// Interval at which a responder repeats a hold-off CTS to keep the connection open (Th).
+pub const TH: Duration = Duration::from_millis(500);
+
+/// The state of an [`RtsCtsOriginator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OriginatorState {
+    WaitingForCts { deadline: Duration },
+    Sending { next_packet: u8, last_packet: u8 },
+    WaitingForEndOfMsgAck { deadline: Duration },
+    Complete,
+    Aborted,
+}
+
+/// Sans-IO state machine sending a payload with the RTS/CTS connection-mode transport protocol.
+///
+/// The originator announces the transfer with an RTS, then sends each window of TP.DT packets
+/// allowed by a CTS from the responder, until the responder acknowledges the transfer with an
+/// EndOfMsgAck. Feed it the received messages with [`handle`](Self::handle), send every frame
+/// returned by [`poll_transmit`](Self::poll_transmit), and call [`poll`](Self::poll)
+/// periodically to detect timeouts.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// # use core::time::Duration;
+/// # fn main() -> Result<(), can_types::error::Error> {
+/// let payload = [0x11; 10];
+/// let now = Duration::from_millis(0);
+/// let mut originator = RtsCtsOriginator::new(
+///     Pgn::from_bits(65260),
+///     SourceAddr::Some(249),
+///     DestinationAddr::Some(0),
+///     &payload,
+///     255,
+///     now,
+/// )?;
+///
+/// // The RTS announces 10 bytes in 2 packets.
+/// let rts = originator.poll_transmit(now).unwrap();
+/// assert_eq!(Pdu::<Data>::from_hex("100A0002FFECFE00"), rts.pdu());
+///
+/// // The responder clears both packets, then acknowledges them.
+/// let cts = Message::<J1939, Data>::from_hex("1CECF900", "110201FFFFECFE00");
+/// originator.handle(&cts, now)?;
+///
+/// assert!(originator.poll_transmit(now).is_some());
+/// assert!(originator.poll_transmit(now).is_some());
+/// assert!(originator.poll_transmit(now).is_none());
+///
+/// let ack = Message::<J1939, Data>::from_hex("1CECF900", "130A0002FFECFE00");
+/// originator.handle(&ack, now)?;
+///
+/// assert!(originator.is_complete());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RtsCtsOriginator<'a> {
+    pgn: Pgn,
+    source_address: u8,
+    destination_address: u8,
+    payload: &'a [u8],
+    packets: u8,
+    max_packets_per_cts: u8,
+    state: OriginatorState,
+    pending: Option<TpCm>,
+}
+
+impl<'a> RtsCtsOriginator<'a> {
+    /// Creates a new originator and queues its RTS.
+    ///
+    /// # Arguments
+    /// - `pgn`: The PGN of the transferred parameter group.
+    /// - `source_address`: The address of the originator.
+    /// - `destination_address`: The address of the responder.
+    /// - `payload`: The payload to send, between 9 and 1785 bytes.
+    /// - `max_packets_per_cts`: The largest number of packets to send per CTS, or 255 for no
+    ///   limit. Zero is treated as one.
+    /// - `now`: The current time.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidDataLength`] if the payload is not between 9 and 1785 bytes.
+    /// - [`Error::MissingSourceAddr`] if `source_address` is [`SourceAddr::None`].
+    /// - [`Error::FieldOutOfRange`] if `destination_address` is [`DestinationAddr::None`] or the
+    ///   global address 255; use a BAM instead.
+    pub fn new(
+        pgn: Pgn,
+        source_address: SourceAddr,
+        destination_address: DestinationAddr,
+        payload: &'a [u8],
+        max_packets_per_cts: u8,
+        now: Duration,
+    ) -> Result<Self, Error> {
+        let packets = check_size(payload.len(), MAX_TP_SIZE)?;
+        let SourceAddr::Some(source_address) = source_address else {
+            return Err(Error::MissingSourceAddr);
+        };
+        let destination_address = match destination_address {
+            DestinationAddr::Some(da) => da,
+            DestinationAddr::None => GLOBAL_ADDRESS,
+        };
+        Error::check_field(
+            "destination_address",
+            destination_address.into(),
+            u32::from(GLOBAL_ADDRESS - 1),
+        )?;
+        let max_packets_per_cts = max_packets_per_cts.max(1);
+
+        Ok(Self {
+            pgn,
+            source_address,
+            destination_address,
+            payload,
+            packets,
+            max_packets_per_cts,
+            state: OriginatorState::WaitingForCts { deadline: now + T3 },
+            pending: Some(TpCm::Rts {
+                #[allow(clippy::cast_possible_truncation)]
+                size: payload.len() as u16,
+                packets,
+                max_packets_per_cts,
+                pgn,
+            }),
+        })
+    }
+
+    /// Returns `true` if the responder acknowledged the whole payload.
+    #[inline]
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        matches!(self.state, OriginatorState::Complete)
+    }
+
+    /// Returns `true` if the transfer was aborted by either side.
+    #[inline]
+    #[must_use]
+    pub const fn is_aborted(&self) -> bool {
+        matches!(self.state, OriginatorState::Aborted)
+    }
+
+    /// Aborts the transfer and queues a Connection Abort with the given reason.
+    pub fn abort(&mut self, reason: AbortReason) {
+        if !matches!(
+            self.state,
+            OriginatorState::Complete | OriginatorState::Aborted
+        ) {
+            self.pending = Some(TpCm::Abort {
+                reason,
+                pgn: self.pgn,
+            });
+            self.state = OriginatorState::Aborted;
+        }
+    }
+
+    /// Aborts the transfer with the given reason and returns the matching error.
+    fn fail(&mut self, reason: AbortReason) -> Error {
+        self.abort(reason);
+        Error::TransportAborted(reason)
+    }
+
+    /// Consumes a received message.
+    ///
+    /// Messages other than a TP.CM from the responder to the originator for the transferred PGN
+    /// are ignored. A CTS for zero packets holds the connection open, and a CTS for packets that
+    /// were already sent requests their retransmission.
+    ///
+    /// # Errors
+    /// - [`Error::TransportAborted`] if the responder aborts, or if it sends a CTS while a
+    ///   window is being sent or a CTS for packets that do not exist. In the latter cases a
+    ///   Connection Abort is queued.
+    pub fn handle(&mut self, message: &Message<J1939, Data>, now: Duration) -> Result<(), Error> {
+        let id = message.id();
+
+        if id.source_address() != SourceAddr::Some(self.destination_address)
+            || id.pgn().destination_address() != DestinationAddr::Some(self.source_address)
+        {
+            return Ok(());
+        }
+
+        let Ok(cm) = TpCm::try_from(message) else {
+            return Ok(());
+        };
+
+        if cm.pgn() != self.pgn {
+            return Ok(());
+        }
+
+        match (self.state, cm) {
+            (OriginatorState::Complete | OriginatorState::Aborted, _) => Ok(()),
+            (_, TpCm::Abort { reason, .. }) => {
+                self.state = OriginatorState::Aborted;
+                Err(Error::TransportAborted(reason))
+            }
+            (OriginatorState::Sending { .. }, TpCm::Cts { .. }) => {
+                Err(self.fail(AbortReason::CtsWhileTransferring))
+            }
+            (_, TpCm::Cts { packets: 0, .. }) => {
+                self.state = OriginatorState::WaitingForCts { deadline: now + T4 };
+                Ok(())
+            }
+            (_, TpCm::Cts { next_packet, .. })
+                if next_packet == 0 || next_packet > self.packets =>
+            {
+                Err(self.fail(AbortReason::BadSequenceNumber))
+            }
+            (
+                _,
+                TpCm::Cts {
+                    packets,
+                    next_packet,
+                    ..
+                },
+            ) => {
+                let window = packets.min(self.max_packets_per_cts);
+                let last_packet = next_packet.saturating_add(window - 1).min(self.packets);
+
+                self.state = OriginatorState::Sending {
+                    next_packet,
+                    last_packet,
+                };
+                Ok(())
+            }
+            (OriginatorState::WaitingForEndOfMsgAck { .. }, TpCm::EndOfMsgAck { .. }) => {
+                self.state = OriginatorState::Complete;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the transfer for a timeout, for use when no message is received.
+    ///
+    /// # Errors
+    /// - [`Error::TransportAborted`] with [`AbortReason::Timeout`] if the responder did not
+    ///   answer in time. A Connection Abort is queued.
+    pub fn poll(&mut self, now: Duration) -> Result<(), Error> {
+        match self.state {
+            OriginatorState::WaitingForCts { deadline }
+            | OriginatorState::WaitingForEndOfMsgAck { deadline }
+                if now > deadline =>
+            {
+                Err(self.fail(AbortReason::Timeout))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the next frame to send, if any.
+    ///
+    /// Call it repeatedly until it returns `None`.
+    pub fn poll_transmit(&mut self, now: Duration) -> Option<Message<J1939, Data>> {
+        let source_address = SourceAddr::Some(self.source_address);
+        let destination_address = DestinationAddr::Some(self.destination_address);
+
+        if let Some(cm) = self.pending.take() {
+            return cm.to_message(source_address, destination_address).ok();
+        }
+
+        let OriginatorState::Sending {
+            next_packet,
+            last_packet,
+        } = self.state
+        else {
+            return None;
+        };
+
+        self.state = match next_packet {
+            n if n < last_packet => OriginatorState::Sending {
+                next_packet: n + 1,
+                last_packet,
+            },
+            n if n < self.packets => OriginatorState::WaitingForCts { deadline: now + T3 },
+            _ => OriginatorState::WaitingForEndOfMsgAck { deadline: now + T3 },
+        };
+
+        TpDt::from_payload(self.payload, next_packet)
+            .to_message(source_address, destination_address)
+            .ok()
+    }
+}
+
+/// The phase of an [`RtsCtsResponder`] session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResponderPhase {
+    AwaitingData { deadline: Duration },
+    Holding { next_hold_at: Duration },
+}
+
+/// The state of a connection-mode transfer received by an [`RtsCtsResponder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ResponderSession {
+    pgn: Pgn,
+    originator: u8,
+    size: usize,
+    packets: u8,
+    max_packets_per_cts: u8,
+    next_packet: u8,
+    last_packet: u8,
+    phase: ResponderPhase,
+}
+
+/// Sans-IO state machine receiving payloads with the RTS/CTS connection-mode transport protocol.
+///
+/// The responder answers an RTS addressed to it with a CTS for a window of at most `window`
+/// packets, reassembles the TP.DT packets into a fixed-capacity buffer of `N` bytes, and closes
+/// the transfer with an EndOfMsgAck. [`hold`](Self::hold) makes it answer the next window with
+/// hold-off CTS messages, repeated every [`TH`], until [`resume`](Self::resume) is called.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// # use core::time::Duration;
+/// # fn main() -> Result<(), can_types::error::Error> {
+/// let now = Duration::from_millis(0);
+/// let mut responder: RtsCtsResponder = RtsCtsResponder::new(0, 16);
+///
+/// let rts = Message::<J1939, Data>::from_hex("1CEC00F9", "100A0002FFECFE00");
+/// responder.handle(&rts, now)?;
+///
+/// // The CTS clears both packets.
+/// let cts = responder.poll_transmit(now).unwrap();
+/// assert_eq!(Pdu::<Data>::from_hex("110201FFFFECFE00"), cts.pdu());
+///
+/// let dt_1 = Message::<J1939, Data>::from_hex("1CEB00F9", "0111111111111111");
+/// let dt_2 = Message::<J1939, Data>::from_hex("1CEB00F9", "021111111111FFFF");
+/// responder.handle(&dt_1, now)?;
+///
+/// let (pgn, source_address, payload) = responder.handle(&dt_2, now)?.unwrap();
+///
+/// assert_eq!(Pgn::from_bits(65260), pgn);
+/// assert_eq!(SourceAddr::Some(249), source_address);
+/// assert_eq!([0x11; 10], payload);
+///
+/// // The EndOfMsgAck closes the transfer.
+/// let ack = responder.poll_transmit(now).unwrap();
+/// assert_eq!(Pdu::<Data>::from_hex("130A0002FFECFE00"), ack.pdu());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RtsCtsResponder<const N: usize = MAX_TP_SIZE> {
+    address: u8,
+    window: u8,
+    holding: bool,
+    buffer: [u8; N],
+    session: Option<ResponderSession>,
+    pending: Option<(TpCm, u8)>,
+    rejected: Option<(TpCm, u8)>,
+}
+
+impl<const N: usize> RtsCtsResponder<N> {
+    /// Creates a new responder.
+    ///
+    /// # Arguments
+    /// - `address`: The address of the responder; RTS messages sent to other addresses are
+    ///   ignored.
+    /// - `window`: The largest number of packets to clear per CTS.
+    #[must_use]
+    pub const fn new(address: u8, window: u8) -> Self {
+        Self {
+            address,
+            window: if window == 0 { 1 } else { window },
+            holding: false,
+            buffer: [0; N],
+            session: None,
+            pending: None,
+            rejected: None,
+        }
+    }
+
+    /// Returns `true` if a transfer is in progress.
+    #[inline]
+    #[must_use]
+    pub const fn is_active(&self) -> bool {
+        self.session.is_some()
+    }
+
+    /// Holds the connection open: the next window is answered with hold-off CTS messages instead
+    /// of a CTS for more packets.
+    #[inline]
+    pub fn hold(&mut self) {
+        self.holding = true;
+    }
+
+    /// Stops holding the connection open, clearing the next window if the transfer is on hold.
+    pub fn resume(&mut self, now: Duration) {
+        self.holding = false;
+
+        if let Some(session) = self.session {
+            if matches!(session.phase, ResponderPhase::Holding { .. }) {
+                self.open_window(session, now);
+            }
+        }
+    }
+
+    /// Aborts the transfer in progress, if any, and queues a Connection Abort with the given
+    /// reason.
+    pub fn abort(&mut self, reason: AbortReason) {
+        if let Some(session) = self.session.take() {
+            self.pending = Some((
+                TpCm::Abort {
+                    reason,
+                    pgn: session.pgn,
+                },
+                session.originator,
+            ));
+        }
+    }
+
+    /// Aborts the transfer with the given reason and returns the matching error.
+    fn fail(&mut self, reason: AbortReason) -> Error {
+        self.abort(reason);
+        Error::TransportAborted(reason)
+    }
+
+    /// Starts the next window of a session, queueing its CTS or putting it on hold.
+    fn open_window(&mut self, mut session: ResponderSession, now: Duration) {
+        if self.holding {
+            session.phase = ResponderPhase::Holding { next_hold_at: now };
+        } else {
+            let window = self.window.min(session.max_packets_per_cts);
+            let packets = window.min(session.packets - session.next_packet + 1);
+
+            session.last_packet = session.next_packet + (packets - 1);
+            session.phase = ResponderPhase::AwaitingData { deadline: now + T2 };
+            self.pending = Some((
+                TpCm::Cts {
+                    packets,
+                    next_packet: session.next_packet,
+                    pgn: session.pgn,
+                },
+                session.originator,
+            ));
+        }
+
+        self.session = Some(session);
+    }
+
+    /// Consumes a received message.
+    ///
+    /// Messages other than a TP.CM or TP.DT addressed to the responder are ignored, as are
+    /// packets from another originator than the transfer in progress. An RTS from another
+    /// originator while a transfer is in progress is answered with a Connection Abort.
+    ///
+    /// # Returns
+    /// - `Some((pgn, source_address, payload))`: If the message completed a transfer. An
+    ///   EndOfMsgAck is queued.
+    /// - `None`: If the message was consumed or ignored.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidDataLength`] if an RTS announces a payload larger than the buffer, or a
+    ///   number of packets that does not match its size. A Connection Abort is queued.
+    /// - [`Error::TransportAborted`] if the originator aborts, or if a packet has a bad or
+    ///   duplicate sequence number. In the latter cases a Connection Abort is queued.
+    pub fn handle(
+        &mut self,
+        message: &Message<J1939, Data>,
+        now: Duration,
+    ) -> Result<Option<Reassembled<'_>>, Error> {
+        let id = message.id();
+
+        if id.pgn().destination_address() != DestinationAddr::Some(self.address) {
+            return Ok(None);
+        }
+
+        let SourceAddr::Some(originator) = id.source_address() else {
+            return Ok(None);
+        };
+        let in_session = matches!(self.session, Some(s) if s.originator == originator);
+
+        match id.pgn().lookup() {
+            Some(KnownPgn::TpCm) => match TpCm::try_from(message)? {
+                TpCm::Rts { pgn, .. } if self.session.is_some() && !in_session => {
+                    let reason = AbortReason::AlreadyInSession;
+                    self.rejected = Some((TpCm::Abort { reason, pgn }, originator));
+                    Ok(None)
+                }
+                TpCm::Rts {
+                    size,
+                    packets,
+                    max_packets_per_cts,
+                    pgn,
+                } => {
+                    let size = usize::from(size);
+                    self.session = None;
+
+                    if check_size(size, N).ok() != Some(packets) {
+                        let reason = match size {
+                            s if s > MAX_TP_SIZE => AbortReason::MessageTooLarge,
+                            _ => AbortReason::ResourcesNeeded,
+                        };
+                        self.pending = Some((TpCm::Abort { reason, pgn }, originator));
+                        return Err(Error::InvalidDataLength(size));
+                    }
+
+                    let session = ResponderSession {
+                        pgn,
+                        originator,
+                        size,
+                        packets,
+                        max_packets_per_cts: max_packets_per_cts.max(1),
+                        next_packet: 1,
+                        last_packet: 0,
+                        phase: ResponderPhase::AwaitingData { deadline: now },
+                    };
+                    self.open_window(session, now);
+
+                    Ok(None)
+                }
+                TpCm::Abort { reason, .. } if in_session => {
+                    self.session = None;
+                    Err(Error::TransportAborted(reason))
+                }
+                _ => Ok(None),
+            },
+            Some(KnownPgn::TpDt) if in_session => self.receive_dt(TpDt::try_from(message)?, now),
+            _ => Ok(None),
+        }
+    }
+
+    /// Consumes a TP.DT packet from the originator of the transfer in progress.
+    fn receive_dt(&mut self, dt: TpDt, now: Duration) -> Result<Option<Reassembled<'_>>, Error> {
+        let Some(mut session) = self.session else {
+            return Ok(None);
+        };
+
+        match session.phase {
+            ResponderPhase::Holding { .. } => {
+                return Err(self.fail(AbortReason::UnexpectedDataTransfer));
+            }
+            _ if dt.sequence != 0 && dt.sequence < session.next_packet => {
+                return Err(self.fail(AbortReason::DuplicateSequenceNumber));
+            }
+            _ if dt.sequence != session.next_packet => {
+                return Err(self.fail(AbortReason::BadSequenceNumber));
+            }
+            ResponderPhase::AwaitingData { .. } => (),
+        }
+
+        let offset = usize::from(dt.sequence - 1) * TP_DT_LEN;
+        let len = TP_DT_LEN.min(session.size - offset);
+        self.buffer[offset..offset + len].copy_from_slice(&dt.data[..len]);
+
+        if dt.sequence == session.packets {
+            self.session = None;
+            self.pending = Some((
+                TpCm::EndOfMsgAck {
+                    #[allow(clippy::cast_possible_truncation)]
+                    size: session.size as u16,
+                    packets: session.packets,
+                    pgn: session.pgn,
+                },
+                session.originator,
+            ));
+
+            return Ok(Some((
+                session.pgn,
+                SourceAddr::Some(session.originator),
+                &self.buffer[..session.size],
+            )));
+        }
+
+        session.next_packet += 1;
+
+        if dt.sequence == session.last_packet {
+            self.open_window(session, now);
+        } else {
+            session.phase = ResponderPhase::AwaitingData { deadline: now + T1 };
+            self.session = Some(session);
+        }
+
+        Ok(None)
+    }
+
+    /// Checks the transfer in progress for a timeout, for use when no message is received.
+    ///
+    /// # Errors
+    /// - [`Error::TransportAborted`] with [`AbortReason::Timeout`] if the originator did not
+    ///   send the next packet in time. A Connection Abort is queued.
+    pub fn poll(&mut self, now: Duration) -> Result<(), Error> {
+        match self.session {
+            Some(ResponderSession {
+                phase: ResponderPhase::AwaitingData { deadline },
+                ..
+            }) if now > deadline => Err(self.fail(AbortReason::Timeout)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the next frame to send, if any.
+    ///
+    /// Call it repeatedly until it returns `None`. While the transfer is on hold, a hold-off CTS
+    /// is returned every [`TH`].
+    pub fn poll_transmit(&mut self, now: Duration) -> Option<Message<J1939, Data>> {
+        let (cm, originator) = match (self.rejected.take(), self.pending.take()) {
+            (Some(rejected), pending) => {
+                self.pending = pending;
+                rejected
+            }
+            (None, Some(pending)) => pending,
+            (None, None) => {
+                let mut session = self.session?;
+
+                let ResponderPhase::Holding { next_hold_at } = session.phase else {
+                    return None;
+                };
+
+                if now < next_hold_at {
+                    return None;
+                }
+
+                session.phase = ResponderPhase::Holding {
+                    next_hold_at: now + TH,
+                };
+                self.session = Some(session);
+
+                (
+                    TpCm::Cts {
+                        packets: 0,
+                        next_packet: 0xFF,
+                        pgn: session.pgn,
+                    },
+                    session.originator,
+                )
+            }
+        };
+
+        cm.to_message(
+            SourceAddr::Some(self.address),
+            DestinationAddr::Some(originator),
+        )
+        .ok()
+    }
+}
+
+#[cfg(test)]
+mod transport_tests {
+    use super::*;
+    use crate::protocol::j1939::identifier::IdJ1939;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_tp_cm() -> Result<(), Error> {
+        let pgn_a = Pgn::from_bits(65260);
+        let cm_a = [
+            TpCm::Rts {
+                size: 17,
+                packets: 3,
+                max_packets_per_cts: 255,
+                pgn: pgn_a,
+            },
+            TpCm::Cts {
+                packets: 2,
+                next_packet: 1,
+                pgn: pgn_a,
+            },
+            TpCm::EndOfMsgAck {
+                size: 17,
+                packets: 3,
+                pgn: pgn_a,
+            },
+            TpCm::Bam {
+                size: 17,
+                packets: 3,
+                pgn: pgn_a,
+            },
+            TpCm::Abort {
+                reason: AbortReason::Timeout,
+                pgn: pgn_a,
+            },
+        ];
+
+        for cm in cm_a {
+            assert_eq!(cm, TpCm::from_bytes(cm.to_bytes())?);
+        }
+
+        assert_eq!(
+            [0x10, 0x11, 0x00, 0x03, 0xFF, 0xEC, 0xFE, 0x00],
+            cm_a[0].to_bytes()
+        );
+        assert_eq!(
+            Err(Error::InvalidControlByte(0x42)),
+            TpCm::from_bytes([0x42, 0, 0, 0, 0, 0, 0, 0])
+        );
+
+        let msg_a = cm_a[3].to_message(SourceAddr::Some(0), DestinationAddr::Some(41))?;
+
+        assert_eq!(
+            DestinationAddr::Some(255),
+            msg_a.id().pgn().destination_address()
+        );
+        assert_eq!(7, msg_a.id().priority());
+        assert_eq!(cm_a[3], TpCm::try_from(&msg_a)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bam_receiver() -> Result<(), Error> {
+        let mut receiver: BamReceiver<16> = BamReceiver::new();
+        let bam = Message::<J1939, Data>::from_hex("1CECFF00", "2011000300CAFE00");
+        let dt_1 = Message::<J1939, Data>::from_hex("1CEBFF00", "0101020304050607");
+        let dt_2 = Message::<J1939, Data>::from_hex("1CEBFF00", "0208090A0B0C0D0E");
+        let dt_3 = Message::<J1939, Data>::from_hex("1CEBFF00", "030F1011FFFFFFFF");
+        let other = Message::<J1939, Data>::from_hex("1CEBFF3D", "0200000000000000");
+
+        // The 17-byte payload does not fit in the 16-byte buffer.
+        assert_eq!(
+            Err(Error::InvalidDataLength(17)),
+            receiver.receive(&bam, ms(0))
+        );
+
+        let mut receiver: BamReceiver = BamReceiver::new();
+
+        assert_eq!(None, receiver.receive(&bam, ms(0))?);
+        assert!(receiver.is_active());
+        assert_eq!(None, receiver.receive(&dt_1, ms(60))?);
+        assert_eq!(None, receiver.receive(&other, ms(80))?);
+        assert_eq!(None, receiver.receive(&dt_2, ms(150))?);
+
+        let (pgn, source_address, payload) = receiver.receive(&dt_3, ms(250))?.unwrap();
+
+        assert_eq!(Pgn::from_bits(65226), pgn);
+        assert_eq!(SourceAddr::Some(0), source_address);
+        assert_eq!(
+            [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17],
+            payload
+        );
+        assert!(!receiver.is_active());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bam_receiver_errors() -> Result<(), Error> {
+        let mut receiver: BamReceiver = BamReceiver::new();
+        let bam = Message::<J1939, Data>::from_hex("1CECFF00", "2011000300CAFE00");
+        let dt_0 = Message::<J1939, Data>::from_hex("1CEBFF00", "0001020304050607");
+        let dt_1 = Message::<J1939, Data>::from_hex("1CEBFF00", "0101020304050607");
+        let dt_3 = Message::<J1939, Data>::from_hex("1CEBFF00", "030F1011FFFFFFFF");
+        let abort = Message::<J1939, Data>::from_hex("1CECFF00", "FF03FFFFFFCAFE00");
+
+        receiver.receive(&bam, ms(0))?;
+
+        assert_eq!(
+            Err(Error::TransportTiming(ms(20))),
+            receiver.receive(&dt_1, ms(20))
+        );
+
+        receiver.receive(&bam, ms(100))?;
+
+        assert_eq!(
+            Err(Error::TransportTiming(ms(250))),
+            receiver.receive(&dt_1, ms(350))
+        );
+
+        receiver.receive(&bam, ms(400))?;
+        receiver.receive(&dt_1, ms(450))?;
+
+        assert_eq!(
+            Err(Error::TransportAborted(
+                AbortReason::DuplicateSequenceNumber
+            )),
+            receiver.receive(&dt_1, ms(500))
+        );
+
+        receiver.receive(&bam, ms(600))?;
+        receiver.receive(&dt_1, ms(650))?;
+
+        assert_eq!(
+            Err(Error::TransportAborted(AbortReason::BadSequenceNumber)),
+            receiver.receive(&dt_3, ms(700))
+        );
+
+        receiver.receive(&bam, ms(750))?;
+
+        assert_eq!(
+            Err(Error::TransportAborted(AbortReason::BadSequenceNumber)),
+            receiver.receive(&dt_0, ms(800))
+        );
+
+        receiver.receive(&bam, ms(800))?;
+
+        assert_eq!(
+            Err(Error::TransportAborted(AbortReason::Timeout)),
+            receiver.receive(&abort, ms(850))
+        );
+
+        receiver.receive(&bam, ms(900))?;
+
+        assert_eq!(Ok(()), receiver.poll(ms(1000)));
+        assert_eq!(
+            Err(Error::TransportTiming(ms(201))),
+            receiver.poll(ms(1101))
+        );
+        assert!(!receiver.is_active());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tp_dt_from_payload() {
+        let payload = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+        assert_eq!(
+            TpDt::new(1, [1, 2, 3, 4, 5, 6, 7]),
+            TpDt::from_payload(&payload, 1)
+        );
+        assert_eq!(
+            TpDt::new(2, [8, 9, 10, 0xFF, 0xFF, 0xFF, 0xFF]),
+            TpDt::from_payload(&payload, 2)
+        );
+    }
+
+    #[test]
+    fn test_segment() -> Result<(), Error> {
+        let payload: [u8; 15] = core::array::from_fn(|i| i as u8);
+        let pgn_a = Pgn::from_bits(65259);
+
+        let mut frames = segment(
+            pgn_a,
+            SourceAddr::Some(249),
+            DestinationAddr::Some(0),
+            &payload,
+        )?;
+        assert_eq!(4, frames.len());
+
+        let rts = frames.next().unwrap();
+        assert_eq!(IdJ1939::from_hex("1CEC00F9"), rts.id());
+        assert_eq!(Pdu::<Data>::from_hex("100F0003FFEBFE00"), rts.pdu());
+
+        let dt_1 = frames.next().unwrap();
+        assert_eq!(IdJ1939::from_hex("1CEB00F9"), dt_1.id());
+        assert_eq!(Pdu::<Data>::from_hex("0100010203040506"), dt_1.pdu());
+
+        let dt_2 = frames.next().unwrap();
+        assert_eq!(Pdu::<Data>::from_hex("020708090A0B0C0D"), dt_2.pdu());
+
+        let dt_3 = frames.next().unwrap();
+        assert_eq!(Pdu::<Data>::from_hex("030EFFFFFFFFFFFF"), dt_3.pdu());
+        assert!(frames.next().is_none());
+
+        // A maximum-size payload is carried by 255 packets.
+        let frames = segment(
+            pgn_a,
+            SourceAddr::Some(249),
+            DestinationAddr::None,
+            &[0x55; MAX_TP_SIZE],
+        )?;
+        assert_eq!(256, frames.len());
+        assert_eq!(256, frames.clone().count());
+        assert_eq!(
+            Pdu::<Data>::from_hex("FF55555555555555"),
+            frames.last().unwrap().pdu()
+        );
+
+        let bam = segment(
+            pgn_a,
+            SourceAddr::Some(249),
+            DestinationAddr::Some(255),
+            &payload,
+        )?
+        .next()
+        .unwrap();
+        assert_eq!(IdJ1939::from_hex("1CECFFF9"), bam.id());
+        assert_eq!(Pdu::<Data>::from_hex("200F0003FFEBFE00"), bam.pdu());
+
+        // A segmented BAM reassembles into the original payload.
+        let mut receiver: BamReceiver = BamReceiver::new();
+        let mut received = None;
+        let frames = segment(
+            pgn_a,
+            SourceAddr::Some(249),
+            DestinationAddr::None,
+            &payload,
+        )?;
+        for (i, frame) in frames.enumerate() {
+            if let Some((_, _, bytes)) = receiver.receive(&frame, ms(i as u64 * 100))? {
+                received = Some(bytes.len());
+            }
+        }
+        assert_eq!(Some(15), received);
+
+        assert_eq!(
+            Err(Error::InvalidDataLength(1786)),
+            segment(
+                pgn_a,
+                SourceAddr::Some(249),
+                DestinationAddr::None,
+                &[0; 1786]
+            )
+            .map(|_| ())
+        );
+        assert_eq!(
+            Err(Error::MissingSourceAddr),
+            segment(pgn_a, SourceAddr::None, DestinationAddr::None, &payload).map(|_| ())
+        );
+
+        Ok(())
+    }
+
+    /// Forwards every frame queued by the originator to the responder.
+    fn forward_originator(
+        originator: &mut RtsCtsOriginator<'_>,
+        responder: &mut RtsCtsResponder<32>,
+        now: Duration,
+        payload: &mut Option<[u8; 20]>,
+    ) -> Result<usize, Error> {
+        let mut count = 0;
+
+        while let Some(frame) = originator.poll_transmit(now) {
+            count += 1;
+
+            if let Some((_, _, bytes)) = responder.handle(&frame, now)? {
+                let mut out = [0; 20];
+                out.copy_from_slice(bytes);
+                *payload = Some(out);
+            }
+        }
+
+        Ok(count)
+    }
+
+    #[test]
+    fn test_rts_cts_session() -> Result<(), Error> {
+        let payload: [u8; 20] = core::array::from_fn(|i| i as u8);
+        let pgn_a = Pgn::from_bits(65259);
+        let mut originator = RtsCtsOriginator::new(
+            pgn_a,
+            SourceAddr::Some(249),
+            DestinationAddr::Some(0),
+            &payload,
+            255,
+            ms(0),
+        )?;
+        let mut responder: RtsCtsResponder<32> = RtsCtsResponder::new(0, 2);
+        let mut received = None;
+
+        // RTS
+        assert_eq!(
+            1,
+            forward_originator(&mut originator, &mut responder, ms(0), &mut received)?
+        );
+
+        // First window: CTS for packets 1-2.
+        let cts = responder.poll_transmit(ms(10)).unwrap();
+        assert_eq!(Pdu::<Data>::from_hex("110201FFFFEBFE00"), cts.pdu());
+        assert_eq!(IdJ1939::from_hex("1CECF900"), cts.id());
+        originator.handle(&cts, ms(10))?;
+
+        // Second window on hold, then resumed.
+        responder.hold();
+        assert_eq!(
+            2,
+            forward_originator(&mut originator, &mut responder, ms(20), &mut received)?
+        );
+
+        let hold = responder.poll_transmit(ms(50)).unwrap();
+        assert_eq!(Pdu::<Data>::from_hex("1100FFFFFFEBFE00"), hold.pdu());
+        assert!(responder.poll_transmit(ms(100)).is_none());
+        assert!(responder.poll_transmit(ms(550)).is_some());
+        originator.handle(&hold, ms(550))?;
+        assert_eq!(Ok(()), originator.poll(ms(1500)));
+
+        responder.resume(ms(600));
+        let cts = responder.poll_transmit(ms(600)).unwrap();
+        assert_eq!(Pdu::<Data>::from_hex("110103FFFFEBFE00"), cts.pdu());
+        originator.handle(&cts, ms(600))?;
+        forward_originator(&mut originator, &mut responder, ms(610), &mut received)?;
+
+        assert_eq!(Some(payload), received);
+
+        let ack = responder.poll_transmit(ms(620)).unwrap();
+        assert_eq!(Pdu::<Data>::from_hex("13140003FFEBFE00"), ack.pdu());
+        assert!(!originator.is_complete());
+        originator.handle(&ack, ms(620))?;
+        assert!(originator.is_complete());
+        assert!(!responder.is_active());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rts_cts_max_size() -> Result<(), Error> {
+        let payload: [u8; MAX_TP_SIZE] = core::array::from_fn(|i| i as u8);
+        let mut originator = RtsCtsOriginator::new(
+            Pgn::from_bits(65259),
+            SourceAddr::Some(249),
+            DestinationAddr::Some(0),
+            &payload,
+            255,
+            ms(0),
+        )?;
+        let mut responder: RtsCtsResponder = RtsCtsResponder::new(0, 16);
+        let mut received = false;
+        let mut windows = 0;
+        let mut now = ms(0);
+
+        // The last window of 15 packets ends on packet 255.
+        loop {
+            while let Some(frame) = originator.poll_transmit(now) {
+                if let Some((_, _, bytes)) = responder.handle(&frame, now)? {
+                    assert_eq!(&payload[..], bytes);
+                    received = true;
+                }
+            }
+
+            let Some(frame) = responder.poll_transmit(now) else {
+                break;
+            };
+            if matches!(TpCm::try_from(&frame)?, TpCm::Cts { .. }) {
+                windows += 1;
+            }
+            originator.handle(&frame, now)?;
+            now += ms(10);
+        }
+
+        assert!(received);
+        assert_eq!(16, windows);
+        assert!(originator.is_complete());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rts_cts_errors() -> Result<(), Error> {
+        let payload = [0x11; 20];
+        let pgn_a = Pgn::from_bits(65259);
+        let rts = Message::<J1939, Data>::from_hex("1CEC00F9", "1014000302EBFE00");
+        let rts_other = Message::<J1939, Data>::from_hex("1CEC003D", "1014000302EBFE00");
+        let dt_0 = Message::<J1939, Data>::from_hex("1CEB00F9", "0011111111111111");
+        let dt_2 = Message::<J1939, Data>::from_hex("1CEB00F9", "0211111111111111");
+        let sa = SourceAddr::Some(249);
+        let da = DestinationAddr::Some(0);
+
+        assert_eq!(
+            Err(Error::InvalidDataLength(8)),
+            RtsCtsOriginator::new(pgn_a, sa, da, &payload[..8], 255, ms(0)).map(|_| ())
+        );
+        assert_eq!(
+            Err(Error::MissingSourceAddr),
+            RtsCtsOriginator::new(pgn_a, SourceAddr::None, da, &payload, 255, ms(0)).map(|_| ())
+        );
+        for da_global in [DestinationAddr::None, DestinationAddr::Some(255)] {
+            assert!(RtsCtsOriginator::new(pgn_a, sa, da_global, &payload, 255, ms(0)).is_err());
+        }
+
+        // A limit of zero packets per CTS is announced as one.
+        let mut originator = RtsCtsOriginator::new(pgn_a, sa, da, &payload, 0, ms(0))?;
+        assert_eq!(
+            Pdu::<Data>::from_hex("1014000301EBFE00"),
+            originator.poll_transmit(ms(0)).unwrap().pdu()
+        );
+
+        // Originator timeout waiting for the first CTS.
+        let mut originator = RtsCtsOriginator::new(pgn_a, sa, da, &payload, 255, ms(0))?;
+        originator.poll_transmit(ms(0));
+
+        assert_eq!(Ok(()), originator.poll(ms(1250)));
+        assert_eq!(
+            Err(Error::TransportAborted(AbortReason::Timeout)),
+            originator.poll(ms(1251))
+        );
+        assert_eq!(
+            Pdu::<Data>::from_hex("FF03FFFFFFEBFE00"),
+            originator.poll_transmit(ms(1251)).unwrap().pdu()
+        );
+        assert!(originator.is_aborted());
+
+        // Responder: busy, bad sequence and timeout.
+        let mut responder: RtsCtsResponder = RtsCtsResponder::new(0, 255);
+        responder.handle(&rts, ms(0))?;
+        responder.handle(&rts_other, ms(0))?;
+
+        let reject = responder.poll_transmit(ms(0)).unwrap();
+        assert_eq!(Pdu::<Data>::from_hex("FF01FFFFFFEBFE00"), reject.pdu());
+        assert_eq!(IdJ1939::from_hex("1CEC3D00"), reject.id());
+        assert_eq!(
+            Pdu::<Data>::from_hex("110201FFFFEBFE00"),
+            responder.poll_transmit(ms(0)).unwrap().pdu()
+        );
+        assert_eq!(
+            Err(Error::TransportAborted(AbortReason::BadSequenceNumber)),
+            responder.handle(&dt_2, ms(10)).map(|_| ())
+        );
+        assert_eq!(
+            Pdu::<Data>::from_hex("FF07FFFFFFEBFE00"),
+            responder.poll_transmit(ms(10)).unwrap().pdu()
+        );
+
+        responder.handle(&rts, ms(20))?;
+        responder.poll_transmit(ms(20));
+        assert_eq!(
+            Err(Error::TransportAborted(AbortReason::BadSequenceNumber)),
+            responder.handle(&dt_0, ms(30)).map(|_| ())
+        );
+        assert_eq!(
+            Pdu::<Data>::from_hex("FF07FFFFFFEBFE00"),
+            responder.poll_transmit(ms(30)).unwrap().pdu()
+        );
+
+        responder.handle(&rts, ms(100))?;
+        assert_eq!(Ok(()), responder.poll(ms(1350)));
+        assert_eq!(
+            Err(Error::TransportAborted(AbortReason::Timeout)),
+            responder.poll(ms(1351))
+        );
+
+        // Oversized RTS.
+        let mut responder: RtsCtsResponder<16> = RtsCtsResponder::new(0, 255);
+        assert_eq!(
+            Err(Error::InvalidDataLength(20)),
+            responder.handle(&rts, ms(0)).map(|_| ())
+        );
+        assert_eq!(
+            Pdu::<Data>::from_hex("FF02FFFFFFEBFE00"),
+            responder.poll_transmit(ms(0)).unwrap().pdu()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,818 @@
+// Copyright (c) 2024 Nathan H. Keough
+//
+// This work is dual-licensed under MIT OR Apache 2.0 (or any later version).
+// You may choose between one of them if you use this work.
+//
+// For further detail, please refer to the individual licenses located at the root of this crate.
+
+//! # Bit-level Signals
+//!
+//! **Description:**
+//! A signal is a raw value packed into an arbitrary range of bits of a data field. Signals are laid
+//! out either in little-endian (Intel) or big-endian (Motorola) byte order, following the start bit
+//! conventions of the DBC file format.
+//!
+//! - **Bit numbering:** Bit `n` is bit `n % 8` of byte `n / 8`, where bit 0 is the least significant
+//!   bit of a byte and byte 0 is the first byte on the wire.
+//! - **Intel (`@1`):** The start bit is the least significant bit of the signal. The signal grows
+//!   towards more significant bits, continuing into the following byte.
+//! - **Motorola (`@0`):** The start bit is the most significant bit of the signal. The signal grows
+//!   towards less significant bits, continuing at bit 7 of the following byte.
+//!
+//! A [`SignalSpec`] adds the scaling, unit and valid range needed to turn a raw value into a
+//! [`PhysicalValue`], such as a J1939 SPN or a DBC signal.
+
+use crate::{
+    conversion::Conversion,
+    error::Error,
+    payload::{Data, Pdu},
+};
+
+/// Represents the byte order of a signal within a data field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// Little-endian (Intel) byte order, `@1` in DBC files.
+    LittleEndian,
+    /// Big-endian (Motorola) byte order, `@0` in DBC files.
+    BigEndian,
+}
+
+/// Represents the raw value of a signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignalValue {
+    /// An unsigned raw value.
+    Unsigned(u64),
+    /// A two's complement signed raw value.
+    Signed(i64),
+}
+
+impl SignalValue {
+    /// Converts the raw value into a 64-bit float.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub const fn as_f64(self) -> f64 {
+        match self {
+            SignalValue::Unsigned(value) => value as f64,
+            SignalValue::Signed(value) => value as f64,
+        }
+    }
+}
+
+/// Computes the right shift that aligns a signal with the least significant bit of a 64-bit word,
+/// along with the mask of the signal once aligned.
+///
+/// The word is the data field read in little-endian order for Intel signals, and in big-endian
+/// order for Motorola signals.
+///
+/// # Errors
+/// - [`Error::InvalidSignal`] if the signal is empty or does not fit in 64 bits.
+const fn signal_layout(
+    start_bit: u8,
+    length: u8,
+    byte_order: ByteOrder,
+) -> Result<(u32, u64), Error> {
+    let invalid = Error::InvalidSignal { start_bit, length };
+    if length == 0 || length > 64 || start_bit > 63 {
+        return Err(invalid);
+    }
+
+    let mask = if length == 64 {
+        u64::MAX
+    } else {
+        (1 << length) - 1
+    };
+
+    match byte_order {
+        ByteOrder::LittleEndian => {
+            if start_bit as u32 + length as u32 > 64 {
+                return Err(invalid);
+            }
+            Ok((start_bit as u32, mask))
+        }
+        ByteOrder::BigEndian => {
+            // Position of the most significant bit, counting from the first bit on the wire.
+            let msb = (start_bit / 8) as u32 * 8 + (7 - start_bit % 8) as u32;
+            if msb + length as u32 > 64 {
+                return Err(invalid);
+            }
+            Ok((64 - msb - length as u32, mask))
+        }
+    }
+}
+
+impl Pdu<Data> {
+    /// Returns the data field as a 64-bit word in the given byte order.
+    fn signal_word(self, byte_order: ByteOrder) -> u64 {
+        match byte_order {
+            ByteOrder::LittleEndian => u64::from_le_bytes(self.to_be_bytes()),
+            ByteOrder::BigEndian => self.into_bits(),
+        }
+    }
+
+    /// Extracts the raw value of a signal from the data field.
+    ///
+    /// # Arguments
+    /// - `start_bit`: The DBC start bit of the signal, see the [module](self) documentation.
+    /// - `length`: The length of the signal in bits, between 1 and 64 inclusive.
+    /// - `byte_order`: The [`ByteOrder`] of the signal.
+    /// - `signed`: Whether the signal is a two's complement signed value.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidSignal`] if the signal does not fit in the data field.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// // EEC1: engine speed in bytes 3-4 (Intel), 0x1AE0 * 0.125 rpm/bit = 860 rpm
+    /// let data_a = Pdu::<Data>::from_hex("FFFFFFE01AFFFFFF");
+    ///
+    /// let raw = data_a.get_signal(24, 16, ByteOrder::LittleEndian, false).unwrap();
+    ///
+    /// assert_eq!(SignalValue::Unsigned(0x1AE0), raw);
+    /// ```
+    pub fn get_signal(
+        &self,
+        start_bit: u8,
+        length: u8,
+        byte_order: ByteOrder,
+        signed: bool,
+    ) -> Result<SignalValue, Error> {
+        let (shift, mask) = signal_layout(start_bit, length, byte_order)?;
+        let raw = (self.signal_word(byte_order) >> shift) & mask;
+
+        if signed {
+            // Move the sign bit of the signal to bit 63, then shift back to sign-extend.
+            let unused = 64 - u32::from(length);
+            #[allow(clippy::cast_possible_wrap)]
+            let value = ((raw << unused) as i64) >> unused;
+
+            Ok(SignalValue::Signed(value))
+        } else {
+            Ok(SignalValue::Unsigned(raw))
+        }
+    }
+
+    /// Inserts the raw value of a signal into the data field, leaving all other bits untouched.
+    ///
+    /// # Arguments
+    /// - `start_bit`: The DBC start bit of the signal, see the [module](self) documentation.
+    /// - `length`: The length of the signal in bits, between 1 and 64 inclusive.
+    /// - `byte_order`: The [`ByteOrder`] of the signal.
+    /// - `value`: The raw [`SignalValue`] to insert.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidSignal`] if the signal does not fit in the data field.
+    /// - [`Error::SignalOutOfRange`] if the value does not fit in `length` bits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let mut data_a = Pdu::<Data>::from_hex("FFFFFFFFFFFFFFFF");
+    ///
+    /// data_a.set_signal(24, 16, ByteOrder::LittleEndian, SignalValue::Unsigned(0x1AE0)).unwrap();
+    ///
+    /// assert_eq!(Pdu::<Data>::from_hex("FFFFFFE01AFFFFFF"), data_a);
+    /// ```
+    pub fn set_signal(
+        &mut self,
+        start_bit: u8,
+        length: u8,
+        byte_order: ByteOrder,
+        value: SignalValue,
+    ) -> Result<(), Error> {
+        let (shift, mask) = signal_layout(start_bit, length, byte_order)?;
+
+        let raw = match value {
+            SignalValue::Unsigned(value) if value <= mask => value,
+            SignalValue::Signed(value) => {
+                // The value fits if sign-extending its lowest `length` bits restores it.
+                let unused = 64 - u32::from(length);
+                if (value << unused) >> unused != value {
+                    return Err(Error::SignalOutOfRange { length });
+                }
+                #[allow(clippy::cast_sign_loss)]
+                let raw = value as u64 & mask;
+                raw
+            }
+            SignalValue::Unsigned(_) => return Err(Error::SignalOutOfRange { length }),
+        };
+
+        let word = (self.signal_word(byte_order) & !(mask << shift)) | (raw << shift);
+        *self = Self::from_bits(match byte_order {
+            ByteOrder::LittleEndian => u64::from_be_bytes(word.to_le_bytes()),
+            ByteOrder::BigEndian => word,
+        });
+
+        Ok(())
+    }
+}
+
+/// Represents the outcome of decoding a signal into a physical value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PhysicalValue {
+    /// A valid physical value.
+    Value(f64),
+    /// The transmitter reported an error for the parameter (J1939 `0xFE…` sentinel).
+    Error,
+    /// The parameter is not available or not supported (J1939 `0xFF…` sentinel).
+    NotAvailable,
+}
+
+impl PhysicalValue {
+    /// Returns the physical value as a 64-bit float, if valid.
+    #[inline]
+    #[must_use]
+    pub const fn as_f64(self) -> Option<f64> {
+        match self {
+            PhysicalValue::Value(value) => Some(value),
+            PhysicalValue::Error | PhysicalValue::NotAvailable => None,
+        }
+    }
+
+    /// Returns the physical value as a 32-bit float, if valid.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn as_f32(self) -> Option<f32> {
+        match self {
+            PhysicalValue::Value(value) => Some(value as f32),
+            PhysicalValue::Error | PhysicalValue::NotAvailable => None,
+        }
+    }
+}
+
+/// Describes how a signal maps between its raw bits and a physical value.
+///
+/// The physical value is `raw * factor + offset`, valid within `min..=max`. When J1939 sentinels
+/// are enabled, the raw values reserved by SAE J1939-71 for "error" and "not available" decode to
+/// [`PhysicalValue::Error`] and [`PhysicalValue::NotAvailable`] instead of a number:
+///
+/// | Length (bits) | Error                  | Not available          |
+/// |---------------|------------------------|------------------------|
+/// | 2 to 7        | all ones but the last  | all ones               |
+/// | 8 or more     | most significant byte `0xFE` | most significant byte `0xFF` |
+///
+/// Sentinels are encoded with every remaining bit set, e.g. `0xFEFF` and `0xFFFF` for 16 bits.
+///
+/// # Examples
+/// ```rust
+/// # use can_types::prelude::*;
+/// // EEC1 engine speed: bytes 3-4, 0.125 rpm/bit, 0 offset, 0 to 8031.875 rpm
+/// let spn_190 = SignalSpec::new(24, 16, ByteOrder::LittleEndian, false)
+///     .with_scale(0.125, 0.0)
+///     .with_range(0.0, 8031.875)
+///     .with_unit("rpm")
+///     .with_sentinels(true);
+///
+/// let data_a = Pdu::<Data>::from_hex("FFFFFFE01AFFFFFF");
+/// let data_b = Pdu::<Data>::from_hex("FFFFFFFFFFFFFFFF");
+///
+/// assert_eq!(Ok(PhysicalValue::Value(860.0)), spn_190.decode(&data_a));
+/// assert_eq!(Ok(PhysicalValue::NotAvailable), spn_190.decode(&data_b));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalSpec {
+    start_bit: u8,
+    length: u8,
+    byte_order: ByteOrder,
+    signed: bool,
+    factor: f64,
+    offset: f64,
+    min: f64,
+    max: f64,
+    unit: &'static str,
+    sentinels: bool,
+}
+
+impl SignalSpec {
+    /// Creates a new signal specification with a factor of 1, an offset of 0, an unbounded range,
+    /// no unit and J1939 sentinels disabled.
+    ///
+    /// # Arguments
+    /// - `start_bit`: The DBC start bit of the signal, see the [module](self) documentation.
+    /// - `length`: The length of the signal in bits, between 1 and 64 inclusive.
+    /// - `byte_order`: The [`ByteOrder`] of the signal.
+    /// - `signed`: Whether the signal is a two's complement signed value.
+    #[must_use]
+    pub const fn new(start_bit: u8, length: u8, byte_order: ByteOrder, signed: bool) -> Self {
+        Self {
+            start_bit,
+            length,
+            byte_order,
+            signed,
+            factor: 1.0,
+            offset: 0.0,
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+            unit: "",
+            sentinels: false,
+        }
+    }
+
+    /// Returns a copy of the specification with the given factor (resolution) and offset.
+    #[must_use]
+    pub const fn with_scale(mut self, factor: f64, offset: f64) -> Self {
+        self.factor = factor;
+        self.offset = offset;
+        self
+    }
+
+    /// Returns a copy of the specification with the given inclusive range of physical values.
+    #[must_use]
+    pub const fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Returns a copy of the specification with the given unit.
+    #[must_use]
+    pub const fn with_unit(mut self, unit: &'static str) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Returns a copy of the specification with J1939 "error" and "not available" sentinels
+    /// enabled or disabled. Sentinels only apply to unsigned signals of at least 2 bits.
+    #[must_use]
+    pub const fn with_sentinels(mut self, sentinels: bool) -> Self {
+        self.sentinels = sentinels;
+        self
+    }
+
+    /// Returns the DBC start bit of the signal.
+    #[inline]
+    #[must_use]
+    pub const fn start_bit(&self) -> u8 {
+        self.start_bit
+    }
+
+    /// Returns the length of the signal in bits.
+    #[inline]
+    #[must_use]
+    pub const fn length(&self) -> u8 {
+        self.length
+    }
+
+    /// Returns the byte order of the signal.
+    #[inline]
+    #[must_use]
+    pub const fn byte_order(&self) -> ByteOrder {
+        self.byte_order
+    }
+
+    /// Returns `true` if the signal is a two's complement signed value.
+    #[inline]
+    #[must_use]
+    pub const fn signed(&self) -> bool {
+        self.signed
+    }
+
+    /// Returns the factor (resolution) of the signal.
+    #[inline]
+    #[must_use]
+    pub const fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// Returns the offset of the signal.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// Returns the smallest valid physical value.
+    #[inline]
+    #[must_use]
+    pub const fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Returns the largest valid physical value.
+    #[inline]
+    #[must_use]
+    pub const fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Returns the unit of the physical value.
+    #[inline]
+    #[must_use]
+    pub const fn unit(&self) -> &'static str {
+        self.unit
+    }
+
+    /// Returns the raw values transmitted for the "error" and "not available" sentinels, if
+    /// enabled, and the number of trailing bits ignored when matching them.
+    const fn sentinel_values(&self) -> Option<(u64, u64, u8)> {
+        if !self.sentinels || self.signed || self.length < 2 || self.length > 64 {
+            return None;
+        }
+
+        let not_available = if self.length == 64 {
+            u64::MAX
+        } else {
+            (1 << self.length) - 1
+        };
+        let ignored = self.length.saturating_sub(8);
+
+        Some((not_available ^ (1 << ignored), not_available, ignored))
+    }
+
+    /// Decodes the raw bits of the signal into a physical value or sentinel.
+    fn decode_raw(&self, pdu: &Pdu<Data>) -> Result<Result<f64, PhysicalValue>, Error> {
+        let raw = pdu.get_signal(self.start_bit, self.length, self.byte_order, self.signed)?;
+
+        if let (Some((error, not_available, ignored)), SignalValue::Unsigned(value)) =
+            (self.sentinel_values(), raw)
+        {
+            if value >> ignored == not_available >> ignored {
+                return Ok(Err(PhysicalValue::NotAvailable));
+            }
+            if value >> ignored == error >> ignored {
+                return Ok(Err(PhysicalValue::Error));
+            }
+        }
+
+        Ok(Ok(raw.as_f64() * self.factor + self.offset))
+    }
+
+    /// Decodes the signal from the data field into a physical value.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidSignal`] if the signal does not fit in the data field.
+    /// - [`Error::PhysicalOutOfRange`] if the physical value lies outside the valid range.
+    pub fn decode(&self, pdu: &Pdu<Data>) -> Result<PhysicalValue, Error> {
+        match self.decode_raw(pdu)? {
+            Ok(value) if value < self.min || value > self.max => Err(Error::PhysicalOutOfRange),
+            Ok(value) => Ok(PhysicalValue::Value(value)),
+            Err(sentinel) => Ok(sentinel),
+        }
+    }
+
+    /// Decodes the signal from the data field into a physical value, clamped to the valid range.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidSignal`] if the signal does not fit in the data field.
+    pub fn decode_clamped(&self, pdu: &Pdu<Data>) -> Result<PhysicalValue, Error> {
+        match self.decode_raw(pdu)? {
+            Ok(value) => Ok(PhysicalValue::Value(self.clamp(value))),
+            Err(sentinel) => Ok(sentinel),
+        }
+    }
+
+    /// Encodes a physical value or sentinel into the signal of the data field.
+    ///
+    /// Physical values are rounded to the nearest raw value.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidSignal`] if the signal does not fit in the data field.
+    /// - [`Error::PhysicalOutOfRange`] if the physical value is not finite or lies outside the
+    ///   valid range.
+    /// - [`Error::SignalOutOfRange`] if the raw value does not fit in the signal, or if a
+    ///   sentinel is encoded while sentinels are disabled.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use can_types::prelude::*;
+    /// let spn_190 = SignalSpec::new(24, 16, ByteOrder::LittleEndian, false)
+    ///     .with_scale(0.125, 0.0)
+    ///     .with_range(0.0, 8031.875);
+    /// let mut data_a = Pdu::<Data>::from_hex("FFFFFFFFFFFFFFFF");
+    ///
+    /// spn_190.encode(&mut data_a, PhysicalValue::Value(860.0)).unwrap();
+    ///
+    /// assert_eq!(Pdu::<Data>::from_hex("FFFFFFE01AFFFFFF"), data_a);
+    /// assert!(spn_190.encode(&mut data_a, PhysicalValue::Value(9000.0)).is_err());
+    /// ```
+    pub fn encode(&self, pdu: &mut Pdu<Data>, value: PhysicalValue) -> Result<(), Error> {
+        if let PhysicalValue::Value(value) = value {
+            if !value.is_finite() || value < self.min || value > self.max {
+                return Err(Error::PhysicalOutOfRange);
+            }
+        }
+
+        self.encode_raw(pdu, value)
+    }
+
+    /// Encodes a physical value or sentinel into the signal of the data field, clamping physical
+    /// values to the valid range first.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidSignal`] if the signal does not fit in the data field.
+    /// - [`Error::PhysicalOutOfRange`] if the physical value is not finite.
+    /// - [`Error::SignalOutOfRange`] if the raw value does not fit in the signal, or if a
+    ///   sentinel is encoded while sentinels are disabled.
+    pub fn encode_clamped(&self, pdu: &mut Pdu<Data>, value: PhysicalValue) -> Result<(), Error> {
+        match value {
+            PhysicalValue::Value(value) if !value.is_finite() => Err(Error::PhysicalOutOfRange),
+            PhysicalValue::Value(value) => {
+                self.encode_raw(pdu, PhysicalValue::Value(self.clamp(value)))
+            }
+            sentinel => self.encode_raw(pdu, sentinel),
+        }
+    }
+
+    /// Clamps a physical value to the valid range.
+    ///
+    /// Unlike [`f64::clamp`], this does not panic when the bounds are swapped or NaN; the upper
+    /// bound wins and NaN bounds are ignored.
+    fn clamp(&self, value: f64) -> f64 {
+        value.max(self.min).min(self.max)
+    }
+
+    /// Encodes a physical value or sentinel into the signal without checking the valid range.
+    fn encode_raw(&self, pdu: &mut Pdu<Data>, value: PhysicalValue) -> Result<(), Error> {
+        let raw = match (value, self.sentinel_values()) {
+            (PhysicalValue::Value(value), _) => {
+                let scaled = (value - self.offset) / self.factor;
+                // Round half away from zero; `core` has no `f64::round` without `std`.
+                let rounded = if scaled < 0.0 {
+                    scaled - 0.5
+                } else {
+                    scaled + 0.5
+                };
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                if self.signed {
+                    SignalValue::Signed(rounded as i64)
+                } else if rounded < 0.0 {
+                    return Err(Error::SignalOutOfRange {
+                        length: self.length,
+                    });
+                } else {
+                    SignalValue::Unsigned(rounded as u64)
+                }
+            }
+            (PhysicalValue::Error, Some((error, _, _))) => SignalValue::Unsigned(error),
+            (PhysicalValue::NotAvailable, Some((_, not_available, _))) => {
+                SignalValue::Unsigned(not_available)
+            }
+            (PhysicalValue::Error | PhysicalValue::NotAvailable, None) => {
+                return Err(Error::SignalOutOfRange {
+                    length: self.length,
+                })
+            }
+        };
+
+        pdu.set_signal(self.start_bit, self.length, self.byte_order, raw)
+    }
+}
+
+#[cfg(test)]
+mod signal_tests {
+    use super::*;
+
+    #[test]
+    fn test_intel_signal() -> Result<(), Error> {
+        let data_a = Pdu::<Data>::from_hex("FFFFFFE01AFFFFFF");
+
+        assert_eq!(
+            SignalValue::Unsigned(0x1AE0),
+            data_a.get_signal(24, 16, ByteOrder::LittleEndian, false)?
+        );
+        // A signal spanning a byte boundary: low nibble of byte 3 and high nibble of byte 4.
+        assert_eq!(
+            SignalValue::Unsigned(0xAE),
+            data_a.get_signal(28, 8, ByteOrder::LittleEndian, false)?
+        );
+        assert_eq!(
+            SignalValue::Signed(-1),
+            data_a.get_signal(0, 8, ByteOrder::LittleEndian, true)?
+        );
+        assert_eq!(
+            SignalValue::Unsigned(u64::from_le_bytes(data_a.to_be_bytes())),
+            data_a.get_signal(0, 64, ByteOrder::LittleEndian, false)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_motorola_signal() -> Result<(), Error> {
+        let data_a = Pdu::<Data>::from_hex("12345678ABCDEF01");
+
+        // Start bit 7 is the most significant bit of byte 0.
+        assert_eq!(
+            SignalValue::Unsigned(0x1234),
+            data_a.get_signal(7, 16, ByteOrder::BigEndian, false)?
+        );
+        // Start bit 3 is bit 3 of byte 0: the low nibble of byte 0 and all of byte 1.
+        assert_eq!(
+            SignalValue::Unsigned(0x234),
+            data_a.get_signal(3, 12, ByteOrder::BigEndian, false)?
+        );
+        assert_eq!(
+            SignalValue::Signed(-85),
+            data_a.get_signal(39, 8, ByteOrder::BigEndian, true)?
+        );
+        assert_eq!(
+            SignalValue::Unsigned(0x1234_5678_ABCD_EF01),
+            data_a.get_signal(7, 64, ByteOrder::BigEndian, false)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_signal() {
+        let data_a = Pdu::<Data>::from_bits(0);
+
+        assert_eq!(
+            Err(Error::InvalidSignal {
+                start_bit: 60,
+                length: 8
+            }),
+            data_a.get_signal(60, 8, ByteOrder::LittleEndian, false)
+        );
+        assert!(data_a
+            .get_signal(0, 0, ByteOrder::LittleEndian, false)
+            .is_err());
+        assert!(data_a
+            .get_signal(56, 9, ByteOrder::BigEndian, false)
+            .is_err());
+        assert!(data_a
+            .get_signal(64, 1, ByteOrder::BigEndian, false)
+            .is_err());
+    }
+
+    #[test]
+    fn test_set_signal() -> Result<(), Error> {
+        let mut data_a = Pdu::<Data>::from_hex("FFFFFFFFFFFFFFFF");
+        data_a.set_signal(
+            24,
+            16,
+            ByteOrder::LittleEndian,
+            SignalValue::Unsigned(0x1AE0),
+        )?;
+
+        assert_eq!(Pdu::<Data>::from_hex("FFFFFFE01AFFFFFF"), data_a);
+
+        let mut data_b = Pdu::<Data>::from_bits(0);
+        data_b.set_signal(3, 12, ByteOrder::BigEndian, SignalValue::Unsigned(0x234))?;
+        data_b.set_signal(39, 8, ByteOrder::BigEndian, SignalValue::Signed(-85))?;
+
+        assert_eq!(Pdu::<Data>::from_hex("02340000AB000000"), data_b);
+        assert_eq!(
+            SignalValue::Signed(-85),
+            data_b.get_signal(39, 8, ByteOrder::BigEndian, true)?
+        );
+
+        assert_eq!(
+            Err(Error::SignalOutOfRange { length: 4 }),
+            data_b.set_signal(0, 4, ByteOrder::LittleEndian, SignalValue::Unsigned(16))
+        );
+        assert_eq!(
+            Err(Error::SignalOutOfRange { length: 4 }),
+            data_b.set_signal(0, 4, ByteOrder::LittleEndian, SignalValue::Signed(8))
+        );
+        assert!(data_b
+            .set_signal(0, 4, ByteOrder::LittleEndian, SignalValue::Signed(-8))
+            .is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_signal_spec_decode() -> Result<(), Error> {
+        // ET1 engine coolant temperature: byte 1, 1 degC/bit, -40 offset, -40 to 210 degC
+        let spn_110 = SignalSpec::new(0, 8, ByteOrder::LittleEndian, false)
+            .with_scale(1.0, -40.0)
+            .with_range(-40.0, 210.0)
+            .with_unit("degC")
+            .with_sentinels(true);
+        let data_a = Pdu::<Data>::from_hex("5AFFFFFFFFFFFFFF");
+        let data_b = Pdu::<Data>::from_hex("FEFFFFFFFFFFFFFF");
+        let data_c = Pdu::<Data>::from_hex("FFFFFFFFFFFFFFFF");
+        let data_d = Pdu::<Data>::from_hex("FBFFFFFFFFFFFFFF");
+
+        assert_eq!(PhysicalValue::Value(50.0), spn_110.decode(&data_a)?);
+        assert_eq!(PhysicalValue::Error, spn_110.decode(&data_b)?);
+        assert_eq!(PhysicalValue::NotAvailable, spn_110.decode(&data_c)?);
+        assert_eq!(Err(Error::PhysicalOutOfRange), spn_110.decode(&data_d));
+        assert_eq!(
+            PhysicalValue::Value(210.0),
+            spn_110.decode_clamped(&data_d)?
+        );
+        assert_eq!(Some(50.0), spn_110.decode(&data_a)?.as_f32());
+        assert_eq!(None, spn_110.decode(&data_c)?.as_f64());
+        assert_eq!("degC", spn_110.unit());
+
+        // 2-bit status: 0b10 is error, 0b11 is not available
+        let status = SignalSpec::new(0, 2, ByteOrder::LittleEndian, false).with_sentinels(true);
+
+        assert_eq!(
+            PhysicalValue::Value(1.0),
+            status.decode(&Pdu::from_bits(1 << 56))?
+        );
+        assert_eq!(
+            PhysicalValue::Error,
+            status.decode(&Pdu::from_bits(2 << 56))?
+        );
+        assert_eq!(
+            PhysicalValue::NotAvailable,
+            status.decode(&Pdu::from_bits(3 << 56))?
+        );
+
+        // Sentinels disabled: all ones is a plain value
+        let plain = SignalSpec::new(0, 8, ByteOrder::LittleEndian, false);
+
+        assert_eq!(PhysicalValue::Value(255.0), plain.decode(&data_c)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_signal_spec_encode() -> Result<(), Error> {
+        let spn_110 = SignalSpec::new(0, 8, ByteOrder::LittleEndian, false)
+            .with_scale(1.0, -40.0)
+            .with_range(-40.0, 210.0)
+            .with_sentinels(true);
+        let spn_190 = SignalSpec::new(24, 16, ByteOrder::LittleEndian, false)
+            .with_scale(0.125, 0.0)
+            .with_range(0.0, 8031.875)
+            .with_sentinels(true);
+        let mut data_a = Pdu::<Data>::from_bits(0);
+
+        spn_110.encode(&mut data_a, PhysicalValue::Value(49.6))?;
+        spn_190.encode(&mut data_a, PhysicalValue::NotAvailable)?;
+
+        assert_eq!(Pdu::<Data>::from_hex("5A0000FFFF000000"), data_a);
+
+        spn_190.encode(&mut data_a, PhysicalValue::Error)?;
+
+        assert_eq!(PhysicalValue::Error, spn_190.decode(&data_a)?);
+        assert_eq!(Pdu::<Data>::from_hex("5A0000FFFE000000"), data_a);
+
+        assert_eq!(
+            Err(Error::PhysicalOutOfRange),
+            spn_110.encode(&mut data_a, PhysicalValue::Value(-41.0))
+        );
+        spn_110.encode_clamped(&mut data_a, PhysicalValue::Value(-41.0))?;
+
+        assert_eq!(PhysicalValue::Value(-40.0), spn_110.decode(&data_a)?);
+
+        let signed = SignalSpec::new(0, 8, ByteOrder::LittleEndian, true).with_scale(0.5, 0.0);
+        signed.encode(&mut data_a, PhysicalValue::Value(-10.0))?;
+
+        assert_eq!(PhysicalValue::Value(-10.0), signed.decode(&data_a)?);
+        assert_eq!(
+            Err(Error::SignalOutOfRange { length: 8 }),
+            signed.encode(&mut data_a, PhysicalValue::NotAvailable)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_signal_spec_non_finite() -> Result<(), Error> {
+        let spn_110 = SignalSpec::new(0, 8, ByteOrder::LittleEndian, false)
+            .with_scale(1.0, -40.0)
+            .with_range(-40.0, 210.0);
+        let unbounded = SignalSpec::new(0, 8, ByteOrder::LittleEndian, false);
+        let mut data_a = Pdu::<Data>::from_hex("5A00000000000000");
+
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                Err(Error::PhysicalOutOfRange),
+                spn_110.encode(&mut data_a, PhysicalValue::Value(value))
+            );
+            assert_eq!(
+                Err(Error::PhysicalOutOfRange),
+                spn_110.encode_clamped(&mut data_a, PhysicalValue::Value(value))
+            );
+            assert_eq!(
+                Err(Error::PhysicalOutOfRange),
+                unbounded.encode(&mut data_a, PhysicalValue::Value(value))
+            );
+        }
+
+        // The data field is left untouched.
+        assert_eq!(Pdu::<Data>::from_hex("5A00000000000000"), data_a);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_signal_spec_swapped_range() -> Result<(), Error> {
+        let swapped =
+            SignalSpec::new(0, 8, ByteOrder::LittleEndian, false).with_range(200.0, 100.0);
+        let nan = SignalSpec::new(0, 8, ByteOrder::LittleEndian, false).with_range(f64::NAN, 100.0);
+        let mut data_a = Pdu::<Data>::from_hex("FA00000000000000");
+
+        assert_eq!(
+            PhysicalValue::Value(100.0),
+            swapped.decode_clamped(&data_a)?
+        );
+        assert_eq!(PhysicalValue::Value(100.0), nan.decode_clamped(&data_a)?);
+
+        swapped.encode_clamped(&mut data_a, PhysicalValue::Value(50.0))?;
+        assert_eq!(Pdu::<Data>::from_hex("6400000000000000"), data_a);
+
+        nan.encode_clamped(&mut data_a, PhysicalValue::Value(50.0))?;
+        assert_eq!(Pdu::<Data>::from_hex("3200000000000000"), data_a);
+
+        Ok(())
+    }
+}